@@ -0,0 +1,138 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Builder, JSONMap, JSONValue, Map, Number, ParseError, ParserConfig, JSON};
+use alloc::borrow::Cow;
+
+pub type JSONMapRef<'a> = Map<Cow<'a, str>, JSONValueRef<'a>>;
+
+/// A parsed value that borrows keys and strings from the input wherever they
+/// contain no escape sequences.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JSONValueRef<'a> {
+    Object(JSONMapRef<'a>),
+    Array(Vec<JSONValueRef<'a>>),
+    String(Cow<'a, str>),
+    Number(f64),
+    Integer(i64),
+    RawNumber(Number),
+    True,
+    False,
+    Null,
+}
+
+impl<'a> JSONValueRef<'a> {
+    pub fn get(&self, key: &str) -> Option<&JSONValueRef<'a>> {
+        match self {
+            JSONValueRef::Object(o) => o.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JSONValueRef::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn into_owned(self) -> JSONValue {
+        match self {
+            JSONValueRef::Object(o) => JSONValue::Object(
+                o.into_iter()
+                    .map(|(k, v)| (k.into(), v.into_owned()))
+                    .collect::<JSONMap>(),
+            ),
+            JSONValueRef::Array(a) => {
+                JSONValue::Array(a.into_iter().map(JSONValueRef::into_owned).collect())
+            }
+            JSONValueRef::String(s) => JSONValue::String(s.into_owned()),
+            JSONValueRef::Number(n) => JSONValue::Number(n),
+            JSONValueRef::Integer(n) => JSONValue::Integer(n),
+            JSONValueRef::RawNumber(s) => JSONValue::RawNumber(s),
+            JSONValueRef::True => JSONValue::True,
+            JSONValueRef::False => JSONValue::False,
+            JSONValueRef::Null => JSONValue::Null,
+        }
+    }
+
+    // Scalars come from the owned parser, which only allocates for them when
+    // keeping number literals.
+    fn from_scalar(value: JSONValue) -> Self {
+        match value {
+            JSONValue::Number(n) => JSONValueRef::Number(n),
+            JSONValue::Integer(n) => JSONValueRef::Integer(n),
+            JSONValue::RawNumber(s) => JSONValueRef::RawNumber(s),
+            JSONValue::True => JSONValueRef::True,
+            JSONValue::False => JSONValueRef::False,
+            JSONValue::Null => JSONValueRef::Null,
+            other => unreachable!("Not a scalar: {}", other.type_name()),
+        }
+    }
+}
+
+// Builds `JSONValueRef`s, borrowing strings and keys where possible.
+struct RefBuilder;
+
+impl<'a> Builder<'a> for RefBuilder {
+    type Value = JSONValueRef<'a>;
+    type Str = Cow<'a, str>;
+    type Object = JSONMapRef<'a>;
+    type Array = Vec<JSONValueRef<'a>>;
+
+    fn read_string(&mut self, parser: &mut JSON<'a>) -> Result<Option<Self::Str>, ParseError> {
+        parser.parse_str()
+    }
+
+    fn string(&mut self, s: Self::Str) -> JSONValueRef<'a> {
+        JSONValueRef::String(s)
+    }
+
+    fn scalar(&mut self, value: JSONValue) -> JSONValueRef<'a> {
+        JSONValueRef::from_scalar(value)
+    }
+
+    fn object(&mut self) -> JSONMapRef<'a> {
+        JSONMapRef::new()
+    }
+
+    fn contains_key(&self, object: &JSONMapRef<'a>, key: &str) -> bool {
+        object.contains_key(key)
+    }
+
+    fn insert(&mut self, object: &mut JSONMapRef<'a>, key: Self::Str, value: JSONValueRef<'a>) {
+        object.insert(key, value);
+    }
+
+    fn end_object(&mut self, object: JSONMapRef<'a>) -> JSONValueRef<'a> {
+        JSONValueRef::Object(object)
+    }
+
+    fn array(&mut self) -> Self::Array {
+        vec![]
+    }
+
+    fn push(&mut self, array: &mut Self::Array, value: JSONValueRef<'a>) {
+        array.push(value);
+    }
+
+    fn end_array(&mut self, array: Self::Array) -> JSONValueRef<'a> {
+        JSONValueRef::Array(array)
+    }
+}
+
+impl<'a> JSON<'a> {
+    /// Keys without escapes point into `json`, so documents with many repeated
+    /// keys, such as arrays of uniform records, allocate nothing per key.
+    pub fn parse_borrowed(json: &'a str) -> Result<JSONValueRef<'a>, ParseError> {
+        JSON::parse_borrowed_with_config(json, &ParserConfig::default())
+    }
+
+    pub fn parse_borrowed_with_config(
+        json: &'a str,
+        config: &ParserConfig,
+    ) -> Result<JSONValueRef<'a>, ParseError> {
+        let mut parser = JSON::new(json);
+        parser.config = config.clone();
+        parser.parse_document_with(&mut RefBuilder)
+    }
+}
@@ -0,0 +1,190 @@
+use crate::DEFAULT_MAX_DEPTH;
+
+/// What to do when an object contains the same key more than once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DuplicateKeyPolicy {
+    LastWins,
+    FirstWins,
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParserConfig {
+    pub(crate) max_depth: usize,
+    pub(crate) allow_comments: bool,
+    pub(crate) allow_trailing_commas: bool,
+    pub(crate) duplicate_keys: DuplicateKeyPolicy,
+    pub(crate) allow_non_finite: bool,
+    pub(crate) recover_errors: bool,
+    pub(crate) max_nodes: usize,
+    pub(crate) max_input_length: usize,
+    pub(crate) preserve_number_literals: bool,
+    pub(crate) max_string_length: usize,
+    pub(crate) case_insensitive_keywords: bool,
+    #[cfg(feature = "intern_keys")]
+    pub(crate) intern_keys: bool,
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        ParserConfig {
+            max_depth: DEFAULT_MAX_DEPTH,
+            allow_comments: false,
+            allow_trailing_commas: false,
+            duplicate_keys: DuplicateKeyPolicy::LastWins,
+            allow_non_finite: false,
+            recover_errors: false,
+            max_nodes: usize::MAX,
+            max_input_length: usize::MAX,
+            preserve_number_literals: false,
+            max_string_length: usize::MAX,
+            case_insensitive_keywords: false,
+            #[cfg(feature = "intern_keys")]
+            intern_keys: false,
+        }
+    }
+}
+
+impl ParserConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Accept `//` line comments and `/* */` block comments wherever
+    /// whitespace is allowed.
+    pub fn allow_comments(mut self, allow: bool) -> Self {
+        self.allow_comments = allow;
+        self
+    }
+
+    /// Accept a single comma before the closing `}` or `]`.
+    pub fn allow_trailing_commas(mut self, allow: bool) -> Self {
+        self.allow_trailing_commas = allow;
+        self
+    }
+
+    pub fn duplicate_keys(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+
+    /// Accept the `NaN`, `Infinity` and `-Infinity` literals.
+    pub fn allow_non_finite(mut self, allow: bool) -> Self {
+        self.allow_non_finite = allow;
+        self
+    }
+
+    /// Keep parsing after a syntax error inside an object or array, skipping
+    /// to the next member. Use `JSON::parse_with_errors` to see every error.
+    /// Exceeding the depth, node or string length limits still ends parsing.
+    pub fn recover_errors(mut self, recover: bool) -> Self {
+        self.recover_errors = recover;
+        self
+    }
+
+    /// Limits the total number of values in a document, counting every
+    /// object, array and scalar, to bound the work done on untrusted input.
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Rejects input longer than `max_input_length` bytes before parsing.
+    /// `JSON::from_reader_with_config` stops reading once it is exceeded.
+    pub fn max_input_length(mut self, max_input_length: usize) -> Self {
+        self.max_input_length = max_input_length;
+        self
+    }
+
+    /// Parse numbers as `JSONValue::RawNumber` holding the literal exactly as
+    /// written, so that serializing reproduces it.
+    pub fn preserve_number_literals(mut self, preserve: bool) -> Self {
+        self.preserve_number_literals = preserve;
+        self
+    }
+
+    /// Limits each string, including object keys, to `max_string_length`
+    /// bytes of UTF-8 after escapes are decoded.
+    pub fn max_string_length(mut self, max_string_length: usize) -> Self {
+        self.max_string_length = max_string_length;
+        self
+    }
+
+    /// Accept keywords in any case, such as `True` or `NULL`, including the
+    /// non-finite literals when those are allowed.
+    pub fn case_insensitive_keywords(mut self, allow: bool) -> Self {
+        self.case_insensitive_keywords = allow;
+        self
+    }
+
+    /// Share one allocation between all occurrences of a key in a document,
+    /// which saves memory on arrays of uniform records at the cost of a
+    /// lookup per key. Needs the `intern_keys` feature.
+    #[cfg(feature = "intern_keys")]
+    pub fn intern_keys(mut self, intern: bool) -> Self {
+        self.intern_keys = intern;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Indent {
+    Spaces(usize),
+    Tab,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SerializerConfig {
+    pub(crate) sort_keys: bool,
+    // `None` writes compact output with no insignificant whitespace.
+    pub(crate) indent: Option<Indent>,
+    pub(crate) ascii_only: bool,
+    pub(crate) allow_non_finite: bool,
+    // Maximum significant digits for floats; `None` writes the shortest
+    // representation that reads back as the same f64.
+    pub(crate) precision: Option<usize>,
+    // Format numbers the way ECMAScript does, as RFC 8785 requires. Only
+    // set by `to_canonical_string`.
+    pub(crate) canonical: bool,
+}
+
+impl SerializerConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sort_keys(mut self, sort: bool) -> Self {
+        self.sort_keys = sort;
+        self
+    }
+
+    pub fn indent(mut self, indent: Indent) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Escape every non-ASCII character as `\uXXXX`.
+    pub fn ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    /// Write non-finite numbers as `NaN`, `Infinity` and `-Infinity` instead
+    /// of null.
+    pub fn allow_non_finite(mut self, allow: bool) -> Self {
+        self.allow_non_finite = allow;
+        self
+    }
+
+    /// Round floats to at most `digits` significant digits, with a minimum of
+    /// one and exact ties going to even. Integers are always written in full.
+    pub fn precision(mut self, digits: usize) -> Self {
+        self.precision = Some(digits.max(1));
+        self
+    }
+}
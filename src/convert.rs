@@ -0,0 +1,260 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::JSONValue;
+use alloc::collections::BTreeMap;
+use core::convert::TryFrom;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::error;
+
+#[derive(Debug, PartialEq)]
+pub enum ConvertError {
+    InvalidType(String),
+    /// A number that has a fraction or doesn't fit the target type.
+    OutOfRange(String),
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConvertError::InvalidType(msg) => write!(f, "Invalid type: {}", msg),
+            ConvertError::OutOfRange(msg) => write!(f, "Out of range: {}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ConvertError {}
+
+fn invalid_type(expected: &str, found: &JSONValue) -> ConvertError {
+    ConvertError::InvalidType(format!(
+        "Expected {}, found {}",
+        expected,
+        found.type_name()
+    ))
+}
+
+/// Builds a value of the implementing type from a parsed document, without
+/// consuming it.
+pub trait FromJson: Sized {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError>;
+}
+
+impl FromJson for JSONValue {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        Ok(v.clone())
+    }
+}
+
+impl FromJson for bool {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        v.as_bool().ok_or_else(|| invalid_type("boolean", v))
+    }
+}
+
+impl FromJson for String {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        v.as_str()
+            .map(String::from)
+            .ok_or_else(|| invalid_type("string", v))
+    }
+}
+
+impl FromJson for f64 {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        v.as_f64().ok_or_else(|| invalid_type("number", v))
+    }
+}
+
+// Integral floats such as `42.0` convert, as with `as_i64` and `as_u64`.
+fn integer<T: TryFrom<W>, W>(
+    v: &JSONValue,
+    wide: Option<W>,
+    name: &str,
+) -> Result<T, ConvertError> {
+    if !v.is_number() {
+        return Err(invalid_type("integer", v));
+    }
+    wide.and_then(|n| T::try_from(n).ok())
+        .ok_or_else(|| ConvertError::OutOfRange(format!("{} is not a valid {}", v, name)))
+}
+
+impl FromJson for i64 {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        integer(v, v.as_i64(), "i64")
+    }
+}
+
+impl FromJson for i32 {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        integer(v, v.as_i64(), "i32")
+    }
+}
+
+impl FromJson for u64 {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        integer(v, v.as_u64(), "u64")
+    }
+}
+
+impl FromJson for u32 {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        integer(v, v.as_u64(), "u32")
+    }
+}
+
+impl FromJson for usize {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        integer(v, v.as_u64(), "usize")
+    }
+}
+
+/// Null converts to `None`.
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        match v {
+            JSONValue::Null => Ok(None),
+            v => T::from_json(v).map(Some),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        match v {
+            JSONValue::Array(a) => a.iter().map(T::from_json).collect(),
+            v => Err(invalid_type("array", v)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FromJson> FromJson for HashMap<String, T> {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        match v {
+            JSONValue::Object(o) => o
+                .iter()
+                .map(|(k, v)| Ok((k.to_string(), T::from_json(v)?)))
+                .collect(),
+            v => Err(invalid_type("object", v)),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for BTreeMap<String, T> {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        match v {
+            JSONValue::Object(o) => o
+                .iter()
+                .map(|(k, v)| Ok((k.to_string(), T::from_json(v)?)))
+                .collect(),
+            v => Err(invalid_type("object", v)),
+        }
+    }
+}
+
+/// The reverse of `FromJson`, for types that can describe themselves as a
+/// `JSONValue` without being consumed.
+pub trait ToJson {
+    fn to_json(&self) -> JSONValue;
+}
+
+impl ToJson for JSONValue {
+    fn to_json(&self) -> JSONValue {
+        self.clone()
+    }
+}
+
+impl ToJson for bool {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(*self)
+    }
+}
+
+impl ToJson for str {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(self)
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(self.as_str())
+    }
+}
+
+impl ToJson for f64 {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(*self)
+    }
+}
+
+impl ToJson for i64 {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(*self)
+    }
+}
+
+impl ToJson for i32 {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(*self)
+    }
+}
+
+impl ToJson for u64 {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(*self)
+    }
+}
+
+impl ToJson for u32 {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(*self)
+    }
+}
+
+impl ToJson for usize {
+    fn to_json(&self) -> JSONValue {
+        JSONValue::from(*self)
+    }
+}
+
+/// `None` becomes null.
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> JSONValue {
+        self.as_ref().map_or(JSONValue::Null, T::to_json)
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn to_json(&self) -> JSONValue {
+        self.iter().map(T::to_json).collect()
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> JSONValue {
+        self.as_slice().to_json()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ToJson> ToJson for HashMap<String, T> {
+    fn to_json(&self) -> JSONValue {
+        self.iter().map(|(k, v)| (k.clone(), v.to_json())).collect()
+    }
+}
+
+impl<T: ToJson> ToJson for BTreeMap<String, T> {
+    fn to_json(&self) -> JSONValue {
+        self.iter().map(|(k, v)| (k.clone(), v.to_json())).collect()
+    }
+}
+
+impl JSONValue {
+    pub fn into_typed<T: FromJson>(self) -> Result<T, ConvertError> {
+        T::from_json(&self)
+    }
+}
@@ -0,0 +1,140 @@
+use crate::pointer::parse_index;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{JSONMap, JSONValue};
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+// Dots and backslashes inside keys are escaped with a backslash so that
+// `{"a.b": 1}` flattens to `a\.b` rather than colliding with `{"a": {"b": 1}}`.
+// An empty key is written as `\e`, keeping `{"": 1}` apart from the scalar
+// root, which has the empty path.
+fn escape_key(key: &str) -> String {
+    if key.is_empty() {
+        return "\\e".to_string();
+    }
+    key.replace('\\', "\\\\").replace('.', "\\.")
+}
+
+fn split_path(path: &str) -> Vec<String> {
+    let mut segments = vec![String::new()];
+    let mut chars = path.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some('e') | None => {}
+                Some(next) => segments.last_mut().unwrap().push(next),
+            },
+            '.' => segments.push(String::new()),
+            ch => segments.last_mut().unwrap().push(ch),
+        }
+    }
+    segments
+}
+
+fn flatten_into(
+    value: &JSONValue,
+    prefix: &str,
+    depth: usize,
+    out: &mut HashMap<String, JSONValue>,
+) {
+    let join = |segment: &str| {
+        if prefix.is_empty() {
+            segment.to_string()
+        } else {
+            format!("{}.{}", prefix, segment)
+        }
+    };
+    match value {
+        JSONValue::Object(o) if !o.is_empty() && depth > 0 => {
+            for (key, child) in o {
+                flatten_into(child, &join(&escape_key(key)), depth - 1, out);
+            }
+        }
+        JSONValue::Array(a) if !a.is_empty() && depth > 0 => {
+            for (i, child) in a.iter().enumerate() {
+                flatten_into(child, &join(&i.to_string()), depth - 1, out);
+            }
+        }
+        leaf => {
+            out.insert(prefix.to_string(), leaf.clone());
+        }
+    }
+}
+
+fn insert_path(target: &mut JSONValue, segments: &[String], value: JSONValue) {
+    let (segment, rest) = match segments.split_first() {
+        Some(split) => split,
+        None => {
+            *target = value;
+            return;
+        }
+    };
+    let index = parse_index(segment);
+    if target.is_null() {
+        *target = match index {
+            Some(_) => JSONValue::Array(vec![]),
+            None => JSONValue::Object(JSONMap::new()),
+        };
+    }
+    // A non-index key under an array turns it into an object keyed by index.
+    if let (JSONValue::Array(a), None) = (&*target, index) {
+        *target = a
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i.to_string(), v.clone()))
+            .collect();
+    }
+    let child = match target {
+        JSONValue::Array(a) => {
+            let index = index.unwrap();
+            if a.len() <= index {
+                a.resize(index + 1, JSONValue::Null);
+            }
+            &mut a[index]
+        }
+        JSONValue::Object(o) => o.entry(segment.into()).or_insert(JSONValue::Null),
+        _ => return,
+    };
+    insert_path(child, rest, value);
+}
+
+impl JSONValue {
+    /// Maps every leaf to a dotted path such as `a.b.0`, where numeric
+    /// segments are array indices. Empty objects and arrays are kept as
+    /// leaves, and a scalar root is stored under the empty key. Dots and
+    /// backslashes in keys are escaped with a backslash, and an empty key is
+    /// written as `\e`.
+    pub fn flatten(&self) -> HashMap<String, JSONValue> {
+        self.flatten_depth(usize::MAX)
+    }
+
+    /// Like `flatten`, but paths are at most `max_depth` segments long; values
+    /// below that are kept whole. A depth of 0 maps "" to the entire value.
+    pub fn flatten_depth(&self, max_depth: usize) -> HashMap<String, JSONValue> {
+        let mut out = HashMap::new();
+        flatten_into(self, "", max_depth, &mut out);
+        out
+    }
+
+    /// Rebuilds the nested value described by `flatten` output. Numeric
+    /// segments create arrays, padding any gaps with null, so an object whose
+    /// keys are all indices comes back as an array.
+    pub fn unflatten(map: &HashMap<String, JSONValue>) -> JSONValue {
+        let mut paths: Vec<_> = map.iter().collect();
+        paths.sort_by_key(|(path, _)| *path);
+        let mut result = JSONValue::Null;
+        for (path, value) in paths {
+            if path.is_empty() {
+                return value.clone();
+            }
+            insert_path(&mut result, &split_path(path), value.clone());
+        }
+        match result {
+            JSONValue::Null => JSONValue::Object(JSONMap::new()),
+            result => result,
+        }
+    }
+}
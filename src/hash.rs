@@ -0,0 +1,104 @@
+use crate::JSONValue;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use std::collections::hash_map::DefaultHasher;
+
+/// Numbers hash by bit pattern, with -0.0 folded into 0.0 and every NaN folded
+/// into one value so that equal numbers always hash alike. Object entries are
+/// combined order-independently since map equality ignores order; without
+/// `std` objects are sorted maps, so entries are simply hashed in order.
+impl Hash for JSONValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            JSONValue::Object(o) => {
+                state.write_u8(0);
+                state.write_usize(o.len());
+                #[cfg(feature = "std")]
+                {
+                    let mut combined: u64 = 0;
+                    for entry in o {
+                        let mut hasher = DefaultHasher::new();
+                        entry.hash(&mut hasher);
+                        combined = combined.wrapping_add(hasher.finish());
+                    }
+                    state.write_u64(combined);
+                }
+                #[cfg(not(feature = "std"))]
+                for entry in o {
+                    entry.hash(state);
+                }
+            }
+            JSONValue::Array(a) => {
+                state.write_u8(1);
+                a.hash(state);
+            }
+            JSONValue::String(s) => {
+                state.write_u8(2);
+                s.hash(state);
+            }
+            JSONValue::Number(n) => {
+                state.write_u8(3);
+                let bits = if *n == 0.0 {
+                    0.0f64.to_bits()
+                } else if n.is_nan() {
+                    f64::NAN.to_bits()
+                } else {
+                    n.to_bits()
+                };
+                state.write_u64(bits);
+            }
+            JSONValue::Integer(n) => {
+                state.write_u8(4);
+                n.hash(state);
+            }
+            JSONValue::RawNumber(s) => {
+                state.write_u8(8);
+                s.hash(state);
+            }
+            JSONValue::True => state.write_u8(5),
+            JSONValue::False => state.write_u8(6),
+            JSONValue::Null => state.write_u8(7),
+        }
+    }
+}
+
+/// `JSONValue` can't be `Eq` because NaN is not equal to itself. This wrapper
+/// treats all NaNs as equal, making values usable as `HashMap` keys and in
+/// `HashSet`s.
+#[derive(Clone, Debug)]
+pub struct HashableValue(pub JSONValue);
+
+fn total_eq(a: &JSONValue, b: &JSONValue) -> bool {
+    match (a, b) {
+        (JSONValue::Object(a), JSONValue::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, val)| b.get(key).is_some_and(|other| total_eq(val, other)))
+        }
+        (JSONValue::Array(a), JSONValue::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| total_eq(a, b))
+        }
+        (JSONValue::Number(a), JSONValue::Number(b)) => a == b || (a.is_nan() && b.is_nan()),
+        (a, b) => a == b,
+    }
+}
+
+impl PartialEq for HashableValue {
+    fn eq(&self, other: &Self) -> bool {
+        total_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for HashableValue {}
+
+impl Hash for HashableValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl From<JSONValue> for HashableValue {
+    fn from(value: JSONValue) -> Self {
+        HashableValue(value)
+    }
+}
@@ -1,31 +1,539 @@
 #![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+#[cfg(feature = "intern_keys")]
+use alloc::collections::BTreeSet;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::iter::FromIterator;
+use core::mem;
+use core::ops::Index;
+use core::str::{self, FromStr};
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::convert::TryFrom;
-use std::iter::FromIterator;
-use std::{char, u16};
+#[cfg(feature = "std")]
+use std::error;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+// Without `std`, the items the standard prelude would provide.
+#[cfg(not(feature = "std"))]
+mod prelude {
+    pub(crate) use alloc::boxed::Box;
+    pub(crate) use alloc::string::{String, ToString};
+    pub(crate) use alloc::vec::Vec;
+    pub(crate) use alloc::{format, vec};
+}
+#[cfg(not(feature = "std"))]
+use prelude::*;
+
+/// Used by the exported macros so they work in `no_std` crates.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+    pub use alloc::vec;
+}
+
+mod borrowed;
+mod config;
+mod convert;
+mod flatten;
+mod hash;
+#[cfg(feature = "intern_keys")]
+mod key;
+#[macro_use]
+mod macros;
+pub mod map;
+mod merge;
+mod number;
+mod ord;
+mod patch;
+mod pointer;
+mod query;
+mod schema;
+mod ser;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde_json")]
+mod serde_json_impl;
+mod spans;
+mod stream;
+mod token;
+mod validate;
+
+pub use borrowed::{JSONMapRef, JSONValueRef};
+pub use config::{DuplicateKeyPolicy, Indent, ParserConfig, SerializerConfig};
+pub use convert::{ConvertError, FromJson, ToJson};
+pub use hash::HashableValue;
+pub use map::{Key, Map};
+pub use merge::ArrayMerge;
+pub use number::Number;
+pub use patch::{diff, PatchError};
+pub use pointer::PathError;
+pub use schema::SchemaError;
+#[cfg(feature = "std")]
+pub use ser::ArrayWriter;
+pub use ser::{escape_string, minify, prettify, stringify, unescape_string};
+pub use spans::Span;
+pub use stream::StreamParser;
+pub use token::{Token, Tokenizer};
 
 pub type JSONResult = Result<JSONValue, ParseError>;
 pub type JSONArray = Vec<JSONValue>;
-pub type JSONMap = HashMap<String, JSONValue>;
+/// Object members. With the `preserve_order` feature they stay in insertion
+/// order, which parsing and serialization both respect; see `Map`.
+pub type JSONMap = Map<Key, JSONValue>;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum JSONValue {
-    Object(HashMap<String, JSONValue>),
+    Object(JSONMap),
     Array(Vec<JSONValue>),
     String(String),
     Number(f64),
+    /// Integer literals that fit in an `i64`. Larger integers and anything
+    /// with a fraction or exponent are parsed as `Number`. Equality is
+    /// structural, so `Integer(1) != Number(1.0)`.
+    Integer(i64),
+    /// A number kept exactly as written, such as `1.10`, produced instead of
+    /// `Number` and `Integer` when `ParserConfig::preserve_number_literals`
+    /// is set. It is written out unchanged. Equality compares the text, so
+    /// `1.10` and `1.1` differ.
+    RawNumber(Number),
     True,
     False,
+    #[default]
     Null,
 }
 
+static NULL: JSONValue = JSONValue::Null;
+
 impl JSONValue {
+    pub fn object() -> JSONValue {
+        JSONValue::Object(JSONMap::new())
+    }
+
+    pub fn array() -> JSONValue {
+        JSONValue::Array(vec![])
+    }
+
+    pub fn null() -> JSONValue {
+        JSONValue::Null
+    }
+
+    /// Sorted maps have no capacity, so without `std` the hint is ignored.
+    pub fn object_with_capacity(capacity: usize) -> JSONValue {
+        JSONValue::Object(JSONMap::with_capacity(capacity))
+    }
+
+    pub fn array_with_capacity(capacity: usize) -> JSONValue {
+        JSONValue::Array(Vec::with_capacity(capacity))
+    }
+
     pub fn unwrap<T: TryFrom<JSONValue>>(self) -> T {
         match T::try_from(self) {
             Ok(val) => val,
             Err(_) => panic!("Tried to unwrap an empty value"),
         }
     }
+
+    pub fn get(&self, key: &str) -> Option<&JSONValue> {
+        match self {
+            JSONValue::Object(o) => o.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn get_index(&self, i: usize) -> Option<&JSONValue> {
+        match self {
+            JSONValue::Array(a) => a.get(i),
+            _ => None,
+        }
+    }
+
+    pub fn is_object(&self) -> bool {
+        matches!(self, JSONValue::Object(_))
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self, JSONValue::Array(_))
+    }
+
+    pub fn is_string(&self) -> bool {
+        matches!(self, JSONValue::String(_))
+    }
+
+    pub fn is_number(&self) -> bool {
+        matches!(
+            self,
+            JSONValue::Number(_) | JSONValue::Integer(_) | JSONValue::RawNumber(_)
+        )
+    }
+
+    pub fn is_boolean(&self) -> bool {
+        matches!(self, JSONValue::True | JSONValue::False)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, JSONValue::Null)
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            JSONValue::Object(_) => "object",
+            JSONValue::Array(_) => "array",
+            JSONValue::String(_) => "string",
+            JSONValue::Number(_) | JSONValue::Integer(_) | JSONValue::RawNumber(_) => "number",
+            JSONValue::True | JSONValue::False => "boolean",
+            JSONValue::Null => "null",
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JSONValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JSONValue::Number(n) => Some(*n),
+            JSONValue::Integer(n) => Some(*n as f64),
+            JSONValue::RawNumber(n) => Some(n.as_f64()),
+            _ => None,
+        }
+    }
+
+    /// Floats convert only when they have no fractional part and fit. The
+    /// upper bounds round up to a power of two, so the ranges are exclusive.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            JSONValue::Integer(n) => Some(*n),
+            JSONValue::RawNumber(n) => n
+                .as_str()
+                .parse()
+                .ok()
+                .or_else(|| JSONValue::Number(n.as_f64()).as_i64()),
+            JSONValue::Number(n)
+                if n % 1.0 == 0.0 && (i64::MIN as f64..i64::MAX as f64).contains(n) =>
+            {
+                Some(*n as i64)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            JSONValue::Integer(n) => u64::try_from(*n).ok(),
+            JSONValue::RawNumber(n) => n
+                .as_str()
+                .parse()
+                .ok()
+                .or_else(|| JSONValue::Number(n.as_f64()).as_u64()),
+            JSONValue::Number(n) if n % 1.0 == 0.0 && (0.0..u64::MAX as f64).contains(n) => {
+                Some(*n as u64)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JSONValue::True => Some(true),
+            JSONValue::False => Some(false),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&JSONArray> {
+        match self {
+            JSONValue::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    pub fn as_array_mut(&mut self) -> Option<&mut JSONArray> {
+        match self {
+            JSONValue::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&JSONMap> {
+        match self {
+            JSONValue::Object(o) => Some(o),
+            _ => None,
+        }
+    }
+
+    pub fn as_object_mut(&mut self) -> Option<&mut JSONMap> {
+        match self {
+            JSONValue::Object(o) => Some(o),
+            _ => None,
+        }
+    }
+
+    /// Unlike the `TryFrom` conversions, this hands the value back if it isn't
+    /// an object.
+    pub fn into_object(self) -> Result<JSONMap, JSONValue> {
+        match self {
+            JSONValue::Object(o) => Ok(o),
+            other => Err(other),
+        }
+    }
+
+    /// Hands the value back if it isn't an array.
+    pub fn into_array(self) -> Result<JSONArray, JSONValue> {
+        match self {
+            JSONValue::Array(a) => Ok(a),
+            other => Err(other),
+        }
+    }
+
+    /// Does nothing and returns `None` if the value isn't an object.
+    pub fn insert(
+        &mut self,
+        key: impl Into<Key>,
+        value: impl Into<JSONValue>,
+    ) -> Option<JSONValue> {
+        match self {
+            JSONValue::Object(o) => o.insert(key.into(), value.into()),
+            _ => None,
+        }
+    }
+
+    /// Does nothing and returns `None` if the value isn't an object.
+    pub fn remove(&mut self, key: &str) -> Option<JSONValue> {
+        match self {
+            JSONValue::Object(o) => o.remove(key),
+            _ => None,
+        }
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// False for every value that isn't an array.
+    pub fn array_contains(&self, needle: &JSONValue) -> bool {
+        self.as_array().is_some_and(|a| a.contains(needle))
+    }
+
+    /// Does nothing if the value isn't an array.
+    pub fn push(&mut self, value: impl Into<JSONValue>) {
+        if let JSONValue::Array(a) = self {
+            a.push(value.into());
+        }
+    }
+
+    /// Returns the value under `key`, inserting `f()` if it is missing. A value
+    /// that isn't an object is first replaced with an empty one, so calls can
+    /// be chained to build nested objects.
+    pub fn get_or_insert_with<F: FnOnce() -> JSONValue>(
+        &mut self,
+        key: &str,
+        f: F,
+    ) -> &mut JSONValue {
+        if !self.is_object() {
+            *self = JSONValue::object();
+        }
+        match self {
+            JSONValue::Object(o) => o.entry(key.into()).or_insert_with(f),
+            _ => unreachable!("Value was just made an object"),
+        }
+    }
+
+    /// Leaves `Null` in place of the returned value.
+    pub fn take(&mut self) -> JSONValue {
+        mem::take(self)
+    }
+
+    pub fn replace(&mut self, value: JSONValue) -> JSONValue {
+        mem::replace(self, value)
+    }
+
+    /// Keeps only the object entries for which `f` returns true.
+    pub fn retain<F: FnMut(&str, &JSONValue) -> bool>(&mut self, mut f: F) {
+        if let JSONValue::Object(o) = self {
+            o.retain(|k, v| f(k, v));
+        }
+    }
+
+    /// Keeps only the array elements for which `f` returns true.
+    pub fn retain_elements<F: FnMut(&JSONValue) -> bool>(&mut self, f: F) {
+        if let JSONValue::Array(a) = self {
+            a.retain(f);
+        }
+    }
+
+    /// Recursively sorts object keys. Only `preserve_order` maps keep an order
+    /// to sort; without `std` objects are always sorted, and hash maps have no
+    /// order, so this just walks the value.
+    pub fn sort_keys(&mut self) {
+        match self {
+            JSONValue::Object(o) => {
+                o.sort_keys();
+                o.values_mut().for_each(JSONValue::sort_keys);
+            }
+            JSONValue::Array(a) => a.iter_mut().for_each(JSONValue::sort_keys),
+            _ => {}
+        }
+    }
+
+    /// Sorts an array's elements with `f`; nested arrays are left alone.
+    pub fn sort_array_by<F: FnMut(&JSONValue, &JSONValue) -> Ordering>(&mut self, f: F) {
+        if let JSONValue::Array(a) = self {
+            a.sort_by(f);
+        }
+    }
+
+    /// Rebuilds the value with `f` applied to every scalar, leaving objects
+    /// and arrays in place.
+    pub fn map_leaves<F: FnMut(JSONValue) -> JSONValue>(self, mut f: F) -> JSONValue {
+        self.map_leaves_with(&mut f)
+    }
+
+    fn map_leaves_with<F: FnMut(JSONValue) -> JSONValue>(self, f: &mut F) -> JSONValue {
+        match self {
+            JSONValue::Object(o) => o
+                .into_iter()
+                .map(|(k, v)| (k, v.map_leaves_with(f)))
+                .collect(),
+            JSONValue::Array(a) => a.into_iter().map(|v| v.map_leaves_with(f)).collect(),
+            leaf => f(leaf),
+        }
+    }
+
+    /// Iterates an object's entries; empty for every other variant.
+    pub fn entries(&self) -> impl Iterator<Item = (&Key, &JSONValue)> {
+        self.as_object().into_iter().flat_map(|o| o.iter())
+    }
+
+    /// Iterates an object's keys; empty for every other variant.
+    pub fn keys(&self) -> impl Iterator<Item = &Key> {
+        self.as_object().into_iter().flat_map(|o| o.keys())
+    }
+
+    /// Iterates an object's values; empty for every other variant.
+    pub fn values(&self) -> impl Iterator<Item = &JSONValue> {
+        self.as_object().into_iter().flat_map(|o| o.values())
+    }
+
+    /// Iterates an array's elements; empty for every other variant.
+    pub fn elements(&self) -> impl Iterator<Item = &JSONValue> {
+        self.as_array().into_iter().flat_map(|a| a.iter())
+    }
+
+    /// Number of entries in an object, elements in an array or characters in
+    /// a string, or `None` for other scalars.
+    pub fn len(&self) -> Option<usize> {
+        match self {
+            JSONValue::Object(o) => Some(o.len()),
+            JSONValue::Array(a) => Some(a.len()),
+            JSONValue::String(s) => Some(s.chars().count()),
+            _ => None,
+        }
+    }
+
+    /// True only for empty objects, arrays and strings.
+    pub fn is_empty(&self) -> bool {
+        self.len() == Some(0)
+    }
+
+    /// The number of values, counting every object, array and scalar, as the
+    /// `max_nodes` parser limit does.
+    pub fn count_nodes(&self) -> usize {
+        1 + self
+            .elements()
+            .chain(self.values())
+            .map(JSONValue::count_nodes)
+            .sum::<usize>()
+    }
+
+    /// How deeply objects and arrays are nested: 0 for a scalar and 1 for a
+    /// container of scalars. A document parses under `max_depth` only if its
+    /// depth is at most that limit.
+    pub fn depth(&self) -> usize {
+        match self {
+            JSONValue::Object(_) | JSONValue::Array(_) => {
+                1 + self
+                    .elements()
+                    .chain(self.values())
+                    .map(JSONValue::depth)
+                    .max()
+                    .unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Roughly how many bytes the value occupies in memory: one `JSONValue` per
+    /// node plus string and key contents. Allocator slack and map bookkeeping
+    /// are not counted.
+    pub fn estimated_size(&self) -> usize {
+        let contents = match self {
+            JSONValue::Object(o) => o
+                .iter()
+                .map(|(k, v)| mem::size_of::<Key>() + k.len() + v.estimated_size())
+                .sum(),
+            JSONValue::Array(a) => a.iter().map(JSONValue::estimated_size).sum(),
+            JSONValue::String(s) => s.len(),
+            _ => 0,
+        };
+        mem::size_of::<JSONValue>() + contents
+    }
+
+    /// Like `==`, but numbers (including integers) match when they differ by
+    /// at most `epsilon`.
+    pub fn approx_eq(&self, other: &JSONValue, epsilon: f64) -> bool {
+        match (self, other) {
+            (JSONValue::Object(a), JSONValue::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter().all(|(key, val)| {
+                        b.get(key)
+                            .is_some_and(|other| val.approx_eq(other, epsilon))
+                    })
+            }
+            (JSONValue::Array(a), JSONValue::Array(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b)
+                        .all(|(val, other)| val.approx_eq(other, epsilon))
+            }
+            (a, b) if a.is_number() && b.is_number() => {
+                let (a, b) = (a.as_f64().unwrap(), b.as_f64().unwrap());
+                a == b || (a - b <= epsilon && b - a <= epsilon)
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+/// Missing keys and out of range indices yield `Null`; indexing a value of the
+/// wrong type panics.
+impl Index<&str> for JSONValue {
+    type Output = JSONValue;
+    fn index(&self, key: &str) -> &JSONValue {
+        match self {
+            JSONValue::Object(o) => o.get(key).unwrap_or(&NULL),
+            _ => panic!("Cannot index into a non-object value with {:?}", key),
+        }
+    }
+}
+
+impl Index<usize> for JSONValue {
+    type Output = JSONValue;
+    fn index(&self, i: usize) -> &JSONValue {
+        match self {
+            JSONValue::Array(a) => a.get(i).unwrap_or(&NULL),
+            _ => panic!("Cannot index into a non-array value with {}", i),
+        }
+    }
 }
 
 impl TryFrom<JSONValue> for JSONMap {
@@ -53,11 +561,27 @@ impl TryFrom<JSONValue> for f64 {
     fn try_from(v: JSONValue) -> Result<Self, Self::Error> {
         match v {
             JSONValue::Number(n) => Ok(n),
+            JSONValue::Integer(n) => Ok(n as f64),
+            JSONValue::RawNumber(n) => Ok(n.as_f64()),
             _ => Err("Invalid type conversion"),
         }
     }
 }
 
+impl TryFrom<JSONValue> for i64 {
+    type Error = &'static str;
+    fn try_from(v: JSONValue) -> Result<Self, Self::Error> {
+        v.as_i64().ok_or("Invalid type conversion")
+    }
+}
+
+impl TryFrom<JSONValue> for u64 {
+    type Error = &'static str;
+    fn try_from(v: JSONValue) -> Result<Self, Self::Error> {
+        v.as_u64().ok_or("Invalid type conversion")
+    }
+}
+
 impl TryFrom<JSONValue> for String {
     type Error = &'static str;
     fn try_from(v: JSONValue) -> Result<Self, Self::Error> {
@@ -79,21 +603,54 @@ impl TryFrom<JSONValue> for bool {
     }
 }
 
+impl TryFrom<&JSONValue> for f64 {
+    type Error = &'static str;
+    fn try_from(v: &JSONValue) -> Result<Self, Self::Error> {
+        v.as_f64().ok_or("Invalid type conversion")
+    }
+}
+
+impl TryFrom<&JSONValue> for bool {
+    type Error = &'static str;
+    fn try_from(v: &JSONValue) -> Result<Self, Self::Error> {
+        v.as_bool().ok_or("Invalid type conversion")
+    }
+}
+
+impl<'a> TryFrom<&'a JSONValue> for &'a str {
+    type Error = &'static str;
+    fn try_from(v: &'a JSONValue) -> Result<Self, Self::Error> {
+        v.as_str().ok_or("Invalid type conversion")
+    }
+}
+
 impl From<JSONValue> for () {
-    fn from(_: JSONValue) -> () {
-        ()
+    fn from(_: JSONValue) {}
+}
+
+#[cfg(feature = "std")]
+impl<T: Into<JSONValue>> From<HashMap<String, T>> for JSONValue {
+    fn from(val: HashMap<String, T>) -> JSONValue {
+        val.into_iter().map(|(k, v)| (k, v.into())).collect()
+    }
+}
+
+impl<T: Into<JSONValue>> From<BTreeMap<String, T>> for JSONValue {
+    fn from(val: BTreeMap<String, T>) -> JSONValue {
+        val.into_iter().map(|(k, v)| (k, v.into())).collect()
     }
 }
 
-impl From<JSONMap> for JSONValue {
-    fn from(val: JSONMap) -> JSONValue {
-        Self::Object(val)
+#[cfg(feature = "preserve_order")]
+impl<T: Into<JSONValue>> From<indexmap::IndexMap<String, T>> for JSONValue {
+    fn from(val: indexmap::IndexMap<String, T>) -> JSONValue {
+        val.into_iter().map(|(k, v)| (k, v.into())).collect()
     }
 }
 
-impl From<JSONArray> for JSONValue {
-    fn from(val: JSONArray) -> JSONValue {
-        Self::Array(val)
+impl<T: Into<JSONValue>> From<Vec<T>> for JSONValue {
+    fn from(val: Vec<T>) -> JSONValue {
+        val.into_iter().map(Into::into).collect()
     }
 }
 
@@ -103,6 +660,39 @@ impl From<f64> for JSONValue {
     }
 }
 
+impl From<i32> for JSONValue {
+    fn from(n: i32) -> Self {
+        Self::Integer(i64::from(n))
+    }
+}
+
+impl From<u32> for JSONValue {
+    fn from(n: u32) -> Self {
+        Self::Integer(i64::from(n))
+    }
+}
+
+impl From<i64> for JSONValue {
+    fn from(n: i64) -> Self {
+        Self::Integer(n)
+    }
+}
+
+impl From<u64> for JSONValue {
+    fn from(n: u64) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Self::Integer(n),
+            Err(_) => Self::Number(n as f64),
+        }
+    }
+}
+
+impl From<usize> for JSONValue {
+    fn from(n: usize) -> Self {
+        Self::from(n as u64)
+    }
+}
+
 impl From<String> for JSONValue {
     fn from(s: String) -> Self {
         Self::String(s)
@@ -130,22 +720,321 @@ impl From<()> for JSONValue {
     }
 }
 
+impl<T: Into<JSONValue>> From<Option<T>> for JSONValue {
+    fn from(val: Option<T>) -> Self {
+        match val {
+            Some(val) => val.into(),
+            None => Self::Null,
+        }
+    }
+}
+
+impl FromIterator<JSONValue> for JSONValue {
+    fn from_iter<I: IntoIterator<Item = JSONValue>>(iter: I) -> Self {
+        Self::Array(iter.into_iter().collect())
+    }
+}
+
+impl FromIterator<(Key, JSONValue)> for JSONValue {
+    fn from_iter<I: IntoIterator<Item = (Key, JSONValue)>>(iter: I) -> Self {
+        Self::Object(iter.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "intern_keys")]
+impl FromIterator<(String, JSONValue)> for JSONValue {
+    fn from_iter<I: IntoIterator<Item = (String, JSONValue)>>(iter: I) -> Self {
+        Self::Object(iter.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+}
+
+/// Iterating a value yields the elements of an array and nothing otherwise.
+impl IntoIterator for JSONValue {
+    type Item = JSONValue;
+    type IntoIter = alloc::vec::IntoIter<JSONValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            JSONValue::Array(a) => a.into_iter(),
+            _ => Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a JSONValue {
+    type Item = &'a JSONValue;
+    type IntoIter = core::slice::Iter<'a, JSONValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            JSONValue::Array(a) => a.iter(),
+            _ => [].iter(),
+        }
+    }
+}
+
+impl FromStr for JSONValue {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JSON::parse(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
-    UnexpectedEndOfInput(String),
-    ExpectedEndOfInput(String),
-    ExpectedObjectKey(String),
-    ExpectedToken(String),
-    UnexpectedToken(String),
-    ExpectedDigit(String),
-    ExpectedEscapeChar(String),
-    ExpectedUnicodeEscape(String),
+    UnexpectedEndOfInput(String, Position),
+    ExpectedEndOfInput(String, Position),
+    ExpectedObjectKey(String, Position),
+    ExpectedToken(String, Position),
+    UnexpectedToken(String, Position),
+    ExpectedDigit(String, Position),
+    ExpectedEscapeChar(String, Position),
+    ExpectedUnicodeEscape(String, Position),
+    InvalidUtf8(String, Position),
+    Io(String, Position),
+    DepthLimitExceeded(String, Position),
+    SizeLimitExceeded(String, Position),
+    DuplicateKey(String, Position),
+    NumberOutOfRange(String, Position),
+    ControlCharacterInString(String, Position),
+}
+
+impl ParseError {
+    fn parts(&self) -> (&'static str, &str, Position) {
+        match self {
+            ParseError::UnexpectedEndOfInput(msg, pos) => ("Unexpected end of input", msg, *pos),
+            ParseError::ExpectedEndOfInput(msg, pos) => ("Expected end of input", msg, *pos),
+            ParseError::ExpectedObjectKey(msg, pos) => ("Expected object key", msg, *pos),
+            ParseError::ExpectedToken(msg, pos) => ("Expected token", msg, *pos),
+            ParseError::UnexpectedToken(msg, pos) => ("Unexpected token", msg, *pos),
+            ParseError::ExpectedDigit(msg, pos) => ("Expected digit", msg, *pos),
+            ParseError::ExpectedEscapeChar(msg, pos) => ("Expected escape character", msg, *pos),
+            ParseError::ExpectedUnicodeEscape(msg, pos) => ("Expected unicode escape", msg, *pos),
+            ParseError::InvalidUtf8(msg, pos) => ("Invalid UTF-8", msg, *pos),
+            ParseError::Io(msg, pos) => ("I/O error", msg, *pos),
+            ParseError::DepthLimitExceeded(msg, pos) => ("Depth limit exceeded", msg, *pos),
+            ParseError::SizeLimitExceeded(msg, pos) => ("Size limit exceeded", msg, *pos),
+            ParseError::DuplicateKey(key, pos) => ("Duplicate key", key, *pos),
+            ParseError::NumberOutOfRange(msg, pos) => ("Number out of range", msg, *pos),
+            ParseError::ControlCharacterInString(msg, pos) => {
+                ("Control character in string", msg, *pos)
+            }
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        self.parts().1
+    }
+
+    pub fn position(&self) -> Position {
+        self.parts().2
+    }
+
+    pub fn line(&self) -> usize {
+        self.position().line
+    }
+
+    pub fn column(&self) -> usize {
+        self.position().column
+    }
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (label, msg, pos) = self.parts();
+        write!(f, "{} at {}: {}", label, pos, msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ParseError {}
+
 #[derive(Debug, PartialEq)]
-pub struct JSON {
-    chars: Vec<char>,
+pub struct JSON<'a> {
+    json: &'a str,
+    // Scanning works on bytes; since every structural character is ASCII, an
+    // offset that stops on one is always a char boundary in `json`.
+    bytes: &'a [u8],
+    // Byte offset where the document starts, past any leading byte order
+    // mark. Positions are counted from here.
+    start: usize,
+    // Byte offset of the next unconsumed character; equals `bytes.len()` at
+    // the end of input.
     i: usize,
+    // Line number of the first character, for input taken from the middle of
+    // a larger text.
+    first_line: usize,
+    depth: usize,
+    // Values parsed so far, checked against `max_nodes`.
+    nodes: usize,
+    config: ParserConfig,
+    // Errors skipped over in recovery mode.
+    errors: Vec<ParseError>,
+}
+
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+// Where decoded string contents go. Validation uses `()` to check escapes
+// without keeping the result.
+trait StringSink {
+    fn push(&mut self, ch: char);
+    fn push_str(&mut self, s: &str);
+}
+
+impl StringSink for String {
+    fn push(&mut self, ch: char) {
+        String::push(self, ch);
+    }
+
+    fn push_str(&mut self, s: &str) {
+        String::push_str(self, s);
+    }
+}
+
+impl StringSink for () {
+    fn push(&mut self, _: char) {}
+
+    fn push_str(&mut self, _: &str) {}
+}
+
+// What the parser does with the values it reads. The parser owns scanning,
+// limits, error recovery and the object and array loops; a builder only
+// decides what to make of each string, scalar and container, so the owned,
+// borrowed, spanned and validating parsers all accept exactly the same input.
+trait Builder<'a> {
+    type Value;
+    type Str: AsRef<str>;
+    type Object;
+    type Array;
+
+    // Reads a string, including object keys, if one starts at the cursor.
+    fn read_string(&mut self, parser: &mut JSON<'a>) -> Result<Option<Self::Str>, ParseError>;
+    fn string(&mut self, s: Self::Str) -> Self::Value;
+    // Numbers and keywords, which the parser builds without allocating.
+    fn scalar(&mut self, value: JSONValue) -> Self::Value;
+
+    fn object(&mut self) -> Self::Object;
+    fn contains_key(&self, object: &Self::Object, key: &str) -> bool;
+    fn insert(&mut self, object: &mut Self::Object, key: Self::Str, value: Self::Value);
+    fn end_object(&mut self, object: Self::Object) -> Self::Value;
+
+    fn array(&mut self) -> Self::Array;
+    fn push(&mut self, array: &mut Self::Array, value: Self::Value);
+    fn end_array(&mut self, array: Self::Array) -> Self::Value;
+
+    // Called around each object member and array element, even if parsing
+    // it fails. `duplicate` is set when the key is already in the object.
+    fn begin_member(&mut self, _key: &str, _duplicate: bool) {}
+    fn begin_element(&mut self, _index: usize) {}
+    fn end_child(&mut self) {}
+
+    // Called with every complete value and where it was found.
+    fn node(&mut self, value: Self::Value, _span: Span) -> Self::Value {
+        value
+    }
+}
+
+// Builds owned `JSONValue`s.
+struct ValueBuilder {
+    // Keys seen so far, when interning them.
+    #[cfg(feature = "intern_keys")]
+    keys: Option<BTreeSet<Key>>,
+}
+
+impl ValueBuilder {
+    #[cfg(not(feature = "intern_keys"))]
+    fn new(_config: &ParserConfig) -> Self {
+        ValueBuilder {}
+    }
+
+    #[cfg(feature = "intern_keys")]
+    fn new(config: &ParserConfig) -> Self {
+        ValueBuilder {
+            keys: if config.intern_keys {
+                Some(BTreeSet::new())
+            } else {
+                None
+            },
+        }
+    }
+
+    #[cfg(not(feature = "intern_keys"))]
+    fn key(&mut self, key: Cow<str>) -> Key {
+        key.into_owned()
+    }
+
+    #[cfg(feature = "intern_keys")]
+    fn key(&mut self, key: Cow<str>) -> Key {
+        match &mut self.keys {
+            Some(keys) => match keys.get(key.as_ref()) {
+                Some(interned) => interned.clone(),
+                None => {
+                    let key = Key::from(key);
+                    keys.insert(key.clone());
+                    key
+                }
+            },
+            None => Key::from(key),
+        }
+    }
+}
+
+impl<'a> Builder<'a> for ValueBuilder {
+    type Value = JSONValue;
+    type Str = Cow<'a, str>;
+    type Object = JSONMap;
+    type Array = JSONArray;
+
+    fn read_string(&mut self, parser: &mut JSON<'a>) -> Result<Option<Self::Str>, ParseError> {
+        parser.parse_str()
+    }
+
+    fn string(&mut self, s: Self::Str) -> JSONValue {
+        JSONValue::String(s.into_owned())
+    }
+
+    fn scalar(&mut self, value: JSONValue) -> JSONValue {
+        value
+    }
+
+    fn object(&mut self) -> JSONMap {
+        JSONMap::new()
+    }
+
+    fn contains_key(&self, object: &JSONMap, key: &str) -> bool {
+        object.contains_key(key)
+    }
+
+    fn insert(&mut self, object: &mut JSONMap, key: Self::Str, value: JSONValue) {
+        let key = self.key(key);
+        object.insert(key, value);
+    }
+
+    fn end_object(&mut self, object: JSONMap) -> JSONValue {
+        JSONValue::Object(object)
+    }
+
+    fn array(&mut self) -> JSONArray {
+        vec![]
+    }
+
+    fn push(&mut self, array: &mut JSONArray, value: JSONValue) {
+        array.push(value);
+    }
+
+    fn end_array(&mut self, array: JSONArray) -> JSONValue {
+        JSONValue::Array(array)
+    }
 }
 
 macro_rules! try_parse {
@@ -158,193 +1047,469 @@ macro_rules! try_parse {
     };
 }
 
-impl JSON {
-    fn new(json: &str) -> Self {
+impl<'a> JSON<'a> {
+    fn new(json: &'a str) -> Self {
+        let start = if json.starts_with('\u{FEFF}') { 3 } else { 0 };
         JSON {
-            chars: json.chars().collect(),
-            i: 0,
+            json,
+            bytes: json.as_bytes(),
+            start,
+            i: start,
+            first_line: 1,
+            depth: 0,
+            nodes: 0,
+            config: ParserConfig::default(),
+            errors: vec![],
         }
     }
 
     fn parse_value(&mut self) -> JSONResult {
-        self.skip_whitespace();
+        self.parse_node(&mut ValueBuilder::new(&self.config))
+    }
+
+    // Parses one value of any type, handing what it finds to `builder`.
+    fn parse_node<B: Builder<'a>>(&mut self, builder: &mut B) -> Result<B::Value, ParseError> {
+        self.skip_whitespace()?;
+        if self.at_end() {
+            return Err(self.error(
+                ParseError::UnexpectedEndOfInput,
+                "Expected a value".to_string(),
+            ));
+        }
+        self.count_node()?;
+        let start = self.i;
+        let value = match self.peek() {
+            Some(b'{') => self.parse_object(builder)?,
+            Some(b'[') => self.parse_array(builder)?,
+            Some(b'"') => {
+                let s = builder
+                    .read_string(self)?
+                    .expect("Input starts with a quote");
+                builder.string(s)
+            }
+            _ => {
+                let scalar = self.parse_scalar()?;
+                builder.scalar(scalar)
+            }
+        };
+        Ok(builder.node(value, Span { start, end: self.i }))
+    }
+
+    // Parses a number or keyword.
+    fn parse_scalar(&mut self) -> JSONResult {
+        if self.config.allow_non_finite {
+            try_parse!(
+                self.parse_keyword("NaN", JSONValue::Number(f64::NAN)),
+                self.parse_keyword("Infinity", JSONValue::Number(f64::INFINITY)),
+                self.parse_keyword("-Infinity", JSONValue::Number(f64::NEG_INFINITY))
+            );
+        }
         try_parse!(
-            self.parse_string(),
             self.parse_number(),
-            self.parse_object(),
-            self.parse_array(),
             self.parse_keyword("true", JSONValue::True),
             self.parse_keyword("false", JSONValue::False),
             self.parse_keyword("null", JSONValue::Null)
         );
-        Err(ParseError::UnexpectedEndOfInput(format!(
-            "Doesn't seem to be valid JSON"
-        )))
+        match self.char_at(self.i) {
+            Some(ch) => Err(self.error(
+                ParseError::UnexpectedToken,
+                format!("Unexpected character '{}'", ch),
+            )),
+            None => Err(self.error(
+                ParseError::UnexpectedEndOfInput,
+                "Doesn't seem to be valid JSON".to_string(),
+            )),
+        }
     }
 
-    fn parse_object(&mut self) -> Result<Option<JSONValue>, ParseError> {
-        if self.chars[self.i] != '{' {
-            return Ok(None);
-        }
+    fn parse_object<B: Builder<'a>>(&mut self, builder: &mut B) -> Result<B::Value, ParseError> {
+        self.enter()?;
         self.increment(1);
-        self.skip_whitespace();
-        let mut result: JSONMap = HashMap::new();
+        self.skip_whitespace()?;
+        let mut object = builder.object();
         let mut initial = true;
-        while self.chars[self.i] != '}' {
-            self.skip_whitespace();
-            if initial == false {
-                self.eat(',')?;
-                self.skip_whitespace();
-            } else {
-                self.skip_whitespace();
+        while self.peek().is_some_and(|ch| ch != b'}') {
+            match self.parse_member(builder, initial, &mut object) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => self.recover(e, b'}')?,
             }
-            let maybe_key = self.parse_string()?;
-            if maybe_key.is_none() {
-                return Err(ParseError::ExpectedObjectKey(format!(
-                    "Expected an object key. Does the object have a trailing comma?"
-                )));
-            }
-            self.skip_whitespace();
-            self.eat(':')?;
-            let key = maybe_key.unwrap().unwrap();
-            let value = self.parse_value()?;
-            result.insert(key, value);
             initial = false;
-            self.skip_whitespace();
+            self.skip_whitespace()?;
         }
-        self.expect_not_end('}')?;
+        self.expect_not_end(b'}')?;
         self.increment(1);
-        Ok(Some(JSONValue::from(result)))
+        self.depth -= 1;
+        Ok(builder.end_object(object))
     }
 
-    fn parse_array(&mut self) -> Result<Option<JSONValue>, ParseError> {
-        if self.chars[self.i] != '[' {
-            return Ok(None);
+    // Parses one `"key": value` entry into `object`, returning false if a
+    // trailing comma ended the object instead.
+    fn parse_member<B: Builder<'a>>(
+        &mut self,
+        builder: &mut B,
+        initial: bool,
+        object: &mut B::Object,
+    ) -> Result<bool, ParseError> {
+        self.skip_whitespace()?;
+        if !initial {
+            self.eat(b',')?;
+            self.skip_whitespace()?;
+            if self.at_trailing_close(b'}') {
+                return Ok(false);
+            }
+        }
+        let key_start = self.i;
+        let key = match builder.read_string(self)? {
+            Some(key) => key,
+            None => {
+                return Err(self.error(
+                    ParseError::ExpectedObjectKey,
+                    "Expected an object key. Does the object have a trailing comma?".to_string(),
+                ))
+            }
+        };
+        self.skip_whitespace()?;
+        self.eat(b':')?;
+        let policy = self.config.duplicate_keys;
+        let duplicate = builder.contains_key(object, key.as_ref());
+        if duplicate && policy == DuplicateKeyPolicy::Error {
+            return Err(ParseError::DuplicateKey(
+                key.as_ref().to_string(),
+                self.position_at(key_start),
+            ));
         }
+        builder.begin_member(key.as_ref(), duplicate);
+        let value = self.parse_node(builder);
+        builder.end_child();
+        let value = value?;
+        if !(duplicate && policy == DuplicateKeyPolicy::FirstWins) {
+            builder.insert(object, key, value);
+        }
+        Ok(true)
+    }
+
+    fn parse_array<B: Builder<'a>>(&mut self, builder: &mut B) -> Result<B::Value, ParseError> {
+        self.enter()?;
         self.increment(1);
-        self.skip_whitespace();
-        let mut result: Vec<JSONValue> = vec![];
+        self.skip_whitespace()?;
+        let mut array = builder.array();
+        let mut len = 0;
         let mut initial = true;
-        while self.chars[self.i] != ']' {
-            self.skip_whitespace();
-            if initial == false {
-                self.eat(',')?;
+        while self.peek().is_some_and(|ch| ch != b']') {
+            match self.parse_element(builder, initial, len) {
+                Ok(Some(value)) => {
+                    builder.push(&mut array, value);
+                    len += 1;
+                }
+                Ok(None) => break,
+                Err(e) => self.recover(e, b']')?,
             }
-            let value = self.parse_value()?;
-            result.push(value);
             initial = false;
+            self.skip_whitespace()?;
         }
-        self.expect_not_end(']')?;
+        self.expect_not_end(b']')?;
         self.increment(1);
-        Ok(Some(JSONValue::from(result)))
+        self.depth -= 1;
+        Ok(builder.end_array(array))
+    }
+
+    // Parses one array element, which will be at `index`, or returns `None`
+    // if a trailing comma ended the array instead.
+    fn parse_element<B: Builder<'a>>(
+        &mut self,
+        builder: &mut B,
+        initial: bool,
+        index: usize,
+    ) -> Result<Option<B::Value>, ParseError> {
+        self.skip_whitespace()?;
+        if !initial {
+            self.eat(b',')?;
+            self.skip_whitespace()?;
+            if self.at_trailing_close(b']') {
+                return Ok(None);
+            }
+        }
+        builder.begin_element(index);
+        let value = self.parse_node(builder);
+        builder.end_child();
+        value.map(Some)
+    }
+
+    // In recovery mode, records the error and skips ahead to the next `,` or
+    // `close` at the current nesting level so the container can carry on.
+    // Otherwise the error is returned as is. Exceeding a limit always stops
+    // parsing, since carrying on would do the work the limit is there to
+    // prevent.
+    fn recover(&mut self, error: ParseError, close: u8) -> Result<(), ParseError> {
+        let fatal = matches!(
+            error,
+            ParseError::SizeLimitExceeded(..) | ParseError::DepthLimitExceeded(..)
+        );
+        if fatal || !self.config.recover_errors {
+            return Err(error);
+        }
+        self.errors.push(error);
+        let mut nesting = 0;
+        while let Some(ch) = self.peek() {
+            match ch {
+                b',' if nesting == 0 => return Ok(()),
+                ch if ch == close && nesting == 0 => return Ok(()),
+                b'{' | b'[' => nesting += 1,
+                b'}' | b']' if nesting > 0 => nesting -= 1,
+                b'"' => {
+                    self.increment(1);
+                    while self.peek().is_some_and(|ch| ch != b'"') {
+                        if self.peek() == Some(b'\\') {
+                            self.increment(1);
+                        }
+                        self.increment(1);
+                    }
+                }
+                _ => {}
+            }
+            self.increment(1);
+        }
+        Ok(())
     }
 
     fn parse_string(&mut self) -> Result<Option<JSONValue>, ParseError> {
-        if self.chars[self.i] != '"' {
+        Ok(self.parse_str()?.map(|s| JSONValue::String(s.into_owned())))
+    }
+
+    // Strings without escapes are borrowed straight from the input; only
+    // those that need unescaping allocate.
+    fn parse_str(&mut self) -> Result<Option<Cow<'a, str>>, ParseError> {
+        if self.peek() != Some(b'"') {
             return Ok(None);
         }
+        let quote = self.i;
         self.increment(1);
+        let start = self.i;
+        let end = self.plain_run_end(start);
+        if self.bytes.get(end) == Some(&b'"') {
+            self.check_string_length(quote, end - start)?;
+            self.i = end + 1;
+            return Ok(Some(Cow::Borrowed(&self.json[start..end])));
+        }
         let mut result = String::new();
-        while self.chars[self.i] != '"' && self.i < self.chars.len() - 1 {
-            if self.chars[self.i] == '\\' {
-                let ch = self.chars[self.i + 1];
-                if ch == '"' {
-                    result.push_str("\"");
-                    self.increment(1);
-                } else if ['\\', '/'].contains(&ch) {
-                    let escaped = ch.escape_default().next().unwrap_or(ch);
-                    result.push(escaped);
-                    self.increment(1);
-                } else if ['b', 'f', 'n', 'r', 't'].contains(&ch) {
+        self.unescape(&mut result)?;
+        Ok(Some(Cow::Owned(result)))
+    }
+
+    fn check_string_length(&self, quote: usize, len: usize) -> Result<(), ParseError> {
+        if len > self.config.max_string_length {
+            return Err(ParseError::SizeLimitExceeded(
+                format!(
+                    "String of {} bytes exceeds the maximum length of {}",
+                    len, self.config.max_string_length
+                ),
+                self.position_at(quote),
+            ));
+        }
+        Ok(())
+    }
+
+    // Decodes the body of a string whose opening quote has been consumed,
+    // leaving the index after the closing quote. The decoded length is
+    // checked as it grows, so an oversized string fails before it is copied.
+    fn unescape<S: StringSink>(&mut self, result: &mut S) -> Result<(), ParseError> {
+        let quote = self.i - 1;
+        let mut len = 0;
+        while self.peek().is_some_and(|ch| ch != b'"') {
+            if self.bytes[self.i] == b'\\' {
+                let ch = match self.bytes.get(self.i + 1) {
+                    Some(&ch) => ch,
+                    None => {
+                        self.increment(1);
+                        break;
+                    }
+                };
+                if [b'"', b'\\', b'/', b'b', b'f', b'n', b'r', b't'].contains(&ch) {
                     let ch = match ch {
-                        'b' => '\u{8}',
-                        'f' => '\x0C',
-                        'n' => '\n',
-                        'r' => '\r',
-                        't' => '\t',
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\x0C',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
                         _ => unreachable!(),
                     };
+                    len += 1;
                     result.push(ch);
                     self.increment(1);
-                } else if ch == 'u' {
-                    if self.chars[self.i + 2].is_ascii_hexdigit()
-                        && self.chars[self.i + 3].is_ascii_hexdigit()
-                        && self.chars[self.i + 4].is_ascii_hexdigit()
-                        && self.chars[self.i + 5].is_ascii_hexdigit()
-                    {
-                        let char_str = String::from_iter(&self.chars[self.i + 2..=self.i + 5]);
-                        let code = u16::from_str_radix(&char_str, 16)
-                            .expect("Failed to parse unicode escape number");
-                        let string = String::from_utf16_lossy(&[code]);
-                        result.push_str(&string);
-                        self.increment(5);
+                } else if ch == b'u' {
+                    let code = match self.unicode_escape_at(self.i + 2) {
+                        Some(code) => code,
+                        None => {
+                            return Err(self.error(
+                                ParseError::ExpectedUnicodeEscape,
+                                "Expected a unicode escape sequence".to_string(),
+                            ))
+                        }
+                    };
+                    if (0xD800..=0xDBFF).contains(&code) {
+                        let low = if self.bytes.get(self.i + 6) == Some(&b'\\')
+                            && self.bytes.get(self.i + 7) == Some(&b'u')
+                        {
+                            self.unicode_escape_at(self.i + 8)
+                        } else {
+                            None
+                        };
+                        match low {
+                            Some(low) if (0xDC00..=0xDFFF).contains(&low) => {
+                                let high = u32::from(code - 0xD800) << 10;
+                                let code = 0x10000 + high + u32::from(low - 0xDC00);
+                                len += 4;
+                                result.push(
+                                    char::from_u32(code).expect("Surrogate pair is a valid char"),
+                                );
+                                self.increment(11);
+                            }
+                            _ => {
+                                return Err(self.error(
+                                    ParseError::ExpectedUnicodeEscape,
+                                    format!("Unpaired high surrogate \\u{:04X}", code),
+                                ))
+                            }
+                        }
+                    } else if (0xDC00..=0xDFFF).contains(&code) {
+                        return Err(self.error(
+                            ParseError::ExpectedUnicodeEscape,
+                            format!("Unpaired low surrogate \\u{:04X}", code),
+                        ));
                     } else {
-                        return Err(ParseError::ExpectedUnicodeEscape(format!(
-                            "Expected a unicode escape sequence"
-                        )));
+                        let ch =
+                            char::from_u32(u32::from(code)).expect("Non-surrogate is a valid char");
+                        len += ch.len_utf8();
+                        result.push(ch);
+                        self.increment(5);
                     }
                 } else {
-                    return Err(ParseError::ExpectedEscapeChar(format!(
-                        "Expected an escape sequence"
-                    )));
+                    return Err(self.error(
+                        ParseError::ExpectedEscapeChar,
+                        "Expected an escape sequence".to_string(),
+                    ));
                 }
+            } else if self.bytes[self.i] < 0x20 {
+                return Err(self.error(
+                    ParseError::ControlCharacterInString,
+                    format!(
+                        "Unescaped control character U+{:04X} in string",
+                        self.bytes[self.i]
+                    ),
+                ));
             } else {
-                result.push(self.chars[self.i]);
+                // Copy the run of plain characters up to the next quote,
+                // escape or control character in one go.
+                let start = self.i;
+                let end = self.plain_run_end(start);
+                len += end - start;
+                self.check_string_length(quote, len)?;
+                result.push_str(&self.json[start..end]);
+                self.i = end - 1;
             }
+            self.check_string_length(quote, len)?;
             self.increment(1);
         }
-        self.expect_not_end('"')?;
+        self.expect_not_end(b'"')?;
         self.increment(1);
-        Ok(Some(JSONValue::from(result)))
+        Ok(())
+    }
+
+    // The end of the run starting at `start` that can be copied from a string
+    // body unchanged.
+    fn plain_run_end(&self, start: usize) -> usize {
+        let mut end = start;
+        while end < self.bytes.len() && !matches!(self.bytes[end], b'"' | b'\\' | 0..=0x1F) {
+            end += 1;
+        }
+        end
+    }
+
+    fn unicode_escape_at(&self, index: usize) -> Option<u16> {
+        let digits = self.bytes.get(index..index + 4)?;
+        if !digits.iter().all(|ch| ch.is_ascii_hexdigit()) {
+            return None;
+        }
+        u16::from_str_radix(&self.json[index..index + 4], 16).ok()
     }
 
     fn parse_number(&mut self) -> Result<Option<JSONValue>, ParseError> {
         let start = self.i;
-        if !(self.chars[start].is_ascii_digit() || self.chars[start] == '-') {
-            return Ok(None);
+        match self.peek() {
+            Some(ch) if ch.is_ascii_digit() || ch == b'-' => {}
+            Some(b'+') => {
+                return Err(self.error(
+                    ParseError::ExpectedDigit,
+                    "Numbers cannot start with '+'".to_string(),
+                ))
+            }
+            Some(b'.') => {
+                return Err(self.error(
+                    ParseError::ExpectedDigit,
+                    "Expected a digit before '.'".to_string(),
+                ))
+            }
+            _ => return Ok(None),
         }
-        let max = self.chars.len() - 1;
         let mut n = start;
-        if self.chars[n] == '-' && n < max {
+        if self.bytes[n] == b'-' {
             n += 1;
             self.expect_digit(start, n)?;
         }
-        while self.chars[n].is_ascii_digit() && n < max {
-            n += 1;
+        if self.bytes[n] == b'0' && self.bytes.get(n + 1).is_some_and(|ch| ch.is_ascii_digit()) {
+            return Err(ParseError::ExpectedDigit(
+                "Numbers cannot have leading zeros".to_string(),
+                self.position_at(n),
+            ));
         }
-        if self.chars[n] == '.' && n < max {
+        n = self.skip_digits(n);
+        if self.bytes.get(n) == Some(&b'.') {
             n += 1;
             self.expect_digit(start, n)?;
-            while self.chars[n].is_ascii_digit() && n < max {
-                n += 1;
-            }
+            n = self.skip_digits(n);
         }
-        if self.chars[n] == 'e' || self.chars[n] == 'E' && n < max {
+        if matches!(self.bytes.get(n), Some(b'e') | Some(b'E')) {
             n += 1;
-            if self.chars[n] == '-' || self.chars[n] == '+' && n < max {
+            if matches!(self.bytes.get(n), Some(b'-') | Some(b'+')) {
                 n += 1;
             }
             self.expect_digit(start, n)?;
-            while self.chars[n].is_ascii_digit() && n < max {
-                n += 1;
-            }
+            n = self.skip_digits(n);
         }
-        if n > start {
-            let mut end = if n < self.chars.len() { n } else { max };
-            if !self.chars[end].is_ascii_digit() {
-                end -= 1;
+        let str = &self.json[start..n];
+        // Out-of-range literals fall through to the error below.
+        if self.config.preserve_number_literals && str.parse::<f64>().is_ok_and(f64::is_finite) {
+            self.increment(str.len());
+            return Ok(Some(JSONValue::RawNumber(Number::from_literal(str))));
+        }
+        if !str.contains(['.', 'e', 'E']) {
+            if let Ok(number) = str.parse::<i64>() {
+                self.increment(str.len());
+                return Ok(Some(JSONValue::from(number)));
             }
-            let str = String::from_iter(&self.chars[start..=end]);
-            match str.parse::<f64>() {
-                Ok(number) => {
-                    self.increment(str.len());
-                    return Ok(Some(JSONValue::from(number)));
-                }
-                Err(e) => Err(ParseError::ExpectedDigit(format!("'{}', {:#?}", str, e))),
+        }
+        match str.parse::<f64>() {
+            Ok(number) if !number.is_finite() => Err(self.error(
+                ParseError::NumberOutOfRange,
+                format!("'{}' is too large to represent", str),
+            )),
+            Ok(number) => {
+                self.increment(str.len());
+                Ok(Some(JSONValue::from(number)))
             }
-        } else {
-            Ok(None)
+            Err(e) => Err(self.error(ParseError::ExpectedDigit, format!("'{}', {:#?}", str, e))),
+        }
+    }
+
+    fn skip_digits(&self, mut n: usize) -> usize {
+        while self.bytes.get(n).is_some_and(|ch| ch.is_ascii_digit()) {
+            n += 1;
         }
+        n
     }
 
     fn parse_keyword(
@@ -352,68 +1517,339 @@ impl JSON {
         search: &str,
         value: JSONValue,
     ) -> Result<Option<JSONValue>, ParseError> {
-        let start = self.i;
-        let end = if self.i + search.len() > self.chars.len() {
-            self.chars.len()
-        } else {
-            self.i + search.len()
-        };
-        let slice = &String::from_iter(&self.chars[start..end]);
-        if slice == search {
-            self.i += search.len();
+        let candidate = self.bytes.get(self.i..self.i + search.len());
+        let matches = candidate.is_some_and(|candidate| {
+            if self.config.case_insensitive_keywords {
+                candidate.eq_ignore_ascii_case(search.as_bytes())
+            } else {
+                candidate == search.as_bytes()
+            }
+        });
+        if matches {
+            self.increment(search.len());
             return Ok(Some(value));
         }
         Ok(None)
     }
 
-    fn skip_whitespace(&mut self) {
-        while self.chars[self.i].is_ascii_whitespace() {
-            self.increment(1);
+    fn skip_whitespace(&mut self) -> Result<(), ParseError> {
+        loop {
+            while self.peek().is_some_and(|ch| ch.is_ascii_whitespace()) {
+                self.increment(1);
+            }
+            if !self.config.allow_comments || self.peek() != Some(b'/') {
+                return Ok(());
+            }
+            match self.bytes.get(self.i + 1) {
+                Some(b'/') => {
+                    self.increment(2);
+                    while self.peek().is_some_and(|ch| ch != b'\n') {
+                        self.increment(1);
+                    }
+                }
+                Some(b'*') => {
+                    self.increment(2);
+                    loop {
+                        match self.peek() {
+                            None => {
+                                return Err(self.error(
+                                    ParseError::UnexpectedEndOfInput,
+                                    "Unterminated block comment".to_string(),
+                                ))
+                            }
+                            Some(b'*') if self.bytes.get(self.i + 1) == Some(&b'/') => {
+                                self.increment(2);
+                                break;
+                            }
+                            Some(_) => self.increment(1),
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
         }
     }
 
-    fn eat(&mut self, ch: char) -> Result<(), ParseError> {
-        if self.chars[self.i] != ch {
-            let msg = format!("Expected {}.", ch);
-            return Err(ParseError::ExpectedToken(msg));
+    fn at_trailing_close(&self, close: u8) -> bool {
+        self.config.allow_trailing_commas && self.peek() == Some(close)
+    }
+
+    fn eat(&mut self, ch: u8) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(next) if next == ch => {
+                self.increment(1);
+                Ok(())
+            }
+            Some(_) => Err(self.error(
+                ParseError::ExpectedToken,
+                format!("Expected {}.", char::from(ch)),
+            )),
+            None => Err(self.error(
+                ParseError::UnexpectedEndOfInput,
+                format!("Unexpected end of input. Expected '{}'", char::from(ch)),
+            )),
         }
-        self.increment(1);
-        Ok(())
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.i).copied()
+    }
+
+    // The full character starting at `index`, for error messages.
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.json.get(index..)?.chars().next()
+    }
+
+    fn at_end(&self) -> bool {
+        self.i >= self.bytes.len()
     }
 
     fn increment(&mut self, amount: usize) {
-        let current = self.i;
-        if current + amount >= self.chars.len() {
-            self.i = self.chars.len() - 1;
-        } else {
-            self.i += amount;
-        }
+        self.i = (self.i + amount).min(self.bytes.len());
     }
 
     fn expect_digit(&mut self, start: usize, end: usize) -> Result<(), ParseError> {
-        let current = String::from_iter(&self.chars[start..end]);
-        if !self.chars[end].is_ascii_digit() {
-            Err(ParseError::ExpectedDigit(format!(
-                "Expected a digit, received '{}' after numeric '{}'",
-                self.chars[end], current
-            )))
-        } else {
-            Ok(())
+        let current = &self.json[start..end];
+        match self.char_at(end) {
+            Some(ch) if ch.is_ascii_digit() => Ok(()),
+            Some(ch) => Err(ParseError::ExpectedDigit(
+                format!(
+                    "Expected a digit, received '{}' after numeric '{}'",
+                    ch, current
+                ),
+                self.position_at(end),
+            )),
+            None => Err(ParseError::ExpectedDigit(
+                format!(
+                    "Expected a digit, reached end of input after numeric '{}'",
+                    current
+                ),
+                self.position_at(end),
+            )),
         }
     }
 
-    fn expect_not_end(&mut self, ch: char) -> Result<(), ParseError> {
-        if self.i == self.chars.len() {
-            Err(ParseError::UnexpectedEndOfInput(format!(
-                "Unexpected end of input. Expected '{}'",
-                ch
-            )))
+    // Called before consuming a closing delimiter; since `increment` stops at
+    // the end of input rather than before it, running out is detected here.
+    fn expect_not_end(&mut self, ch: u8) -> Result<(), ParseError> {
+        if self.at_end() {
+            Err(self.error(
+                ParseError::UnexpectedEndOfInput,
+                format!("Unexpected end of input. Expected '{}'", char::from(ch)),
+            ))
         } else {
             Ok(())
         }
     }
 
+    // Columns count characters, so UTF-8 continuation bytes are skipped.
+    fn position_at(&self, index: usize) -> Position {
+        let mut position = Position {
+            line: self.first_line,
+            column: 1,
+        };
+        let end = index.clamp(self.start, self.bytes.len());
+        for &byte in &self.bytes[self.start..end] {
+            if byte == b'\n' {
+                position.line += 1;
+                position.column = 1;
+            } else if byte & 0xC0 != 0x80 {
+                position.column += 1;
+            }
+        }
+        position
+    }
+
+    fn error(&self, kind: fn(String, Position) -> ParseError, msg: String) -> ParseError {
+        kind(msg, self.position_at(self.i))
+    }
+
+    fn enter(&mut self) -> Result<(), ParseError> {
+        if self.depth >= self.config.max_depth {
+            return Err(self.error(
+                ParseError::DepthLimitExceeded,
+                format!(
+                    "Nesting exceeds the maximum depth of {}",
+                    self.config.max_depth
+                ),
+            ));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn count_node(&mut self) -> Result<(), ParseError> {
+        self.nodes += 1;
+        if self.nodes > self.config.max_nodes {
+            return Err(self.error(
+                ParseError::SizeLimitExceeded,
+                format!(
+                    "Document has more than the maximum of {} values",
+                    self.config.max_nodes
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_input_length(&self) -> Result<(), ParseError> {
+        if self.bytes.len() > self.config.max_input_length {
+            return Err(ParseError::SizeLimitExceeded(
+                format!(
+                    "Input of {} bytes exceeds the maximum length of {}",
+                    self.bytes.len(),
+                    self.config.max_input_length
+                ),
+                self.position_at(self.start),
+            ));
+        }
+        Ok(())
+    }
+
+    fn expect_end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace()?;
+        match self.char_at(self.i) {
+            None => Ok(()),
+            Some(ch) => Err(self.error(
+                ParseError::ExpectedEndOfInput,
+                format!("Unexpected trailing '{}'", ch),
+            )),
+        }
+    }
+
+    fn parse_document(&mut self) -> JSONResult {
+        self.parse_document_with(&mut ValueBuilder::new(&self.config))
+    }
+
+    // Parses a complete document. In recovery mode, the first error found is
+    // returned.
+    fn parse_document_with<B: Builder<'a>>(
+        &mut self,
+        builder: &mut B,
+    ) -> Result<B::Value, ParseError> {
+        self.check_input_length()?;
+        let value = self.parse_node(builder)?;
+        self.expect_end()?;
+        if !self.errors.is_empty() {
+            return Err(self.errors.remove(0));
+        }
+        Ok(value)
+    }
+
     pub fn parse(json: &str) -> JSONResult {
-        JSON::new(json).parse_value()
+        JSON::new(json).parse_document()
+    }
+
+    pub fn parse_with_depth(json: &str, max_depth: usize) -> JSONResult {
+        JSON::parse_with_config(json, &ParserConfig::new().max_depth(max_depth))
+    }
+
+    pub fn parse_with_config(json: &str, config: &ParserConfig) -> JSONResult {
+        let mut parser = JSON::new(json);
+        parser.config = config.clone();
+        parser.parse_document()
+    }
+
+    /// Parses newline-delimited JSON, yielding one result per non-blank line.
+    /// A malformed line produces an error and parsing resumes on the next one;
+    /// error positions are relative to the whole input.
+    pub fn parse_many(input: &str) -> impl Iterator<Item = JSONResult> + '_ {
+        input
+            .split('\n')
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(n, line)| {
+                let mut parser = JSON::new(line);
+                parser.first_line = n + 1;
+                // Only the input as a whole may start with a byte order mark.
+                if n > 0 {
+                    parser.start = 0;
+                    parser.i = 0;
+                }
+                parser.parse_document()
+            })
+    }
+
+    /// Like `parse_with_config`, but returns every error found. With
+    /// `recover_errors` set, parsing continues past syntax errors inside
+    /// objects and arrays; otherwise the list holds at most one error.
+    pub fn parse_with_errors(
+        json: &str,
+        config: &ParserConfig,
+    ) -> Result<JSONValue, Vec<ParseError>> {
+        let mut parser = JSON::new(json);
+        parser.config = config.clone();
+        let result = parser.check_input_length().and_then(|()| {
+            let value = parser.parse_value()?;
+            parser.expect_end()?;
+            Ok(value)
+        });
+        match result {
+            Ok(value) if parser.errors.is_empty() => Ok(value),
+            Ok(_) => Err(parser.errors),
+            Err(e) => {
+                parser.errors.push(e);
+                Err(parser.errors)
+            }
+        }
+    }
+
+    pub fn parse_bytes(bytes: &[u8]) -> JSONResult {
+        JSON::parse_bytes_with_config(bytes, &ParserConfig::default())
+    }
+
+    pub fn parse_bytes_with_config(bytes: &[u8], config: &ParserConfig) -> JSONResult {
+        match str::from_utf8(bytes) {
+            Ok(json) => JSON::parse_with_config(json, config),
+            Err(e) => {
+                let valid = str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default();
+                let parser = JSON::new(valid);
+                Err(ParseError::InvalidUtf8(
+                    format!("Invalid UTF-8 sequence at byte {}", e.valid_up_to()),
+                    parser.position_at(parser.bytes.len()),
+                ))
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: Read>(reader: R) -> JSONResult {
+        JSON::from_reader_with_config(reader, &ParserConfig::default())
+    }
+
+    /// Same as `from_reader`, which already reads unbuffered sources such as
+    /// files and sockets in large chunks rather than byte by byte.
+    #[cfg(feature = "std")]
+    pub fn from_reader_buffered<R: Read>(reader: R) -> JSONResult {
+        JSON::from_reader_with_config(reader, &ParserConfig::default())
+    }
+
+    /// Reads the whole document, then parses it with `config`. Reading stops
+    /// as soon as the input grows past `max_input_length`, so an oversized
+    /// upload is rejected without being buffered in full. I/O errors report
+    /// the position reached in the data read so far.
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_config<R: Read>(reader: R, config: &ParserConfig) -> JSONResult {
+        let limit = config.max_input_length;
+        // One byte past the limit is enough to tell that it was exceeded.
+        let mut reader = reader.take((limit as u64).saturating_add(1));
+        let mut bytes = Vec::new();
+        if let Err(e) = reader.read_to_end(&mut bytes) {
+            return Err(JSON::io_error(e, &bytes));
+        }
+        if bytes.len() > limit {
+            return Err(ParseError::SizeLimitExceeded(
+                format!("Input exceeds the maximum length of {}", limit),
+                Position { line: 1, column: 1 },
+            ));
+        }
+        JSON::parse_bytes_with_config(&bytes, config)
+    }
+
+    #[cfg(feature = "std")]
+    fn io_error(e: io::Error, bytes: &[u8]) -> ParseError {
+        let text = String::from_utf8_lossy(bytes);
+        let parser = JSON::new(&text);
+        ParseError::Io(e.to_string(), parser.position_at(parser.bytes.len()))
     }
 }
@@ -0,0 +1,66 @@
+/// Builds a `JSONValue` from JSON-like syntax.
+///
+/// Values are converted with `JSONValue::from`, so any expression with a
+/// `From` impl can be interpolated. Object keys are string literals or a
+/// parenthesized expression producing a `String` or `&str`.
+#[macro_export]
+macro_rules! json {
+    (null) => {
+        $crate::JSONValue::Null
+    };
+    ([]) => {
+        $crate::JSONValue::Array($crate::__private::vec![])
+    };
+    ([ $($tt:tt)+ ]) => {
+        $crate::JSONValue::Array($crate::json_internal!(@array [] $($tt)+))
+    };
+    ({}) => {
+        $crate::JSONValue::Object($crate::JSONMap::new())
+    };
+    ({ $($tt:tt)+ }) => {{
+        let mut object = $crate::JSONMap::new();
+        $crate::json_internal!(@object object $($tt)+);
+        $crate::JSONValue::Object(object)
+    }};
+    ($other:expr) => {
+        $crate::JSONValue::from($other)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! json_internal {
+    (@array [$($elems:expr,)*]) => {
+        $crate::__private::vec![$($elems,)*]
+    };
+    (@array [$($elems:expr,)*] null $(, $($rest:tt)*)?) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json!(null),] $($($rest)*)?)
+    };
+    (@array [$($elems:expr,)*] [$($array:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json!([$($array)*]),] $($($rest)*)?)
+    };
+    (@array [$($elems:expr,)*] {$($object:tt)*} $(, $($rest:tt)*)?) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json!({$($object)*}),] $($($rest)*)?)
+    };
+    (@array [$($elems:expr,)*] $next:expr $(, $($rest:tt)*)?) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json!($next),] $($($rest)*)?)
+    };
+
+    (@object $object:ident) => {};
+    (@object $object:ident $key:tt : null $(, $($rest:tt)*)?) => {
+        $object.insert($crate::Key::from($key), $crate::json!(null));
+        $crate::json_internal!(@object $object $($($rest)*)?);
+    };
+    (@object $object:ident $key:tt : [$($array:tt)*] $(, $($rest:tt)*)?) => {
+        $object.insert($crate::Key::from($key), $crate::json!([$($array)*]));
+        $crate::json_internal!(@object $object $($($rest)*)?);
+    };
+    (@object $object:ident $key:tt : {$($map:tt)*} $(, $($rest:tt)*)?) => {
+        $object.insert($crate::Key::from($key), $crate::json!({$($map)*}));
+        $crate::json_internal!(@object $object $($($rest)*)?);
+    };
+    (@object $object:ident $key:tt : $value:expr $(, $($rest:tt)*)?) => {
+        $object.insert($crate::Key::from($key), $crate::json!($value));
+        $crate::json_internal!(@object $object $($($rest)*)?);
+    };
+}
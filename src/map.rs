@@ -0,0 +1,259 @@
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::Hash;
+use core::iter::FromIterator;
+use core::ops::Index;
+
+#[cfg(all(not(feature = "std"), not(feature = "intern_keys")))]
+use crate::prelude::*;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::{btree_map as imp, BTreeMap as Inner};
+#[cfg(feature = "preserve_order")]
+use indexmap::{map as imp, IndexMap as Inner};
+#[cfg(all(feature = "std", not(feature = "preserve_order")))]
+use std::collections::{hash_map as imp, HashMap as Inner};
+
+/// A map with the same API whichever backing store the enabled features
+/// select. By default it is a `HashMap` and iterates in no particular order.
+/// With `preserve_order` it keeps keys in insertion order, and without `std`
+/// it keeps them sorted. Code written against `Map` compiles unchanged under
+/// every combination of features; only the iteration order differs.
+pub struct Map<K, V>(Inner<K, V>);
+
+impl<K: Ord + Hash, V> Map<K, V> {
+    pub fn new() -> Self {
+        Map(Inner::new())
+    }
+
+    /// Sorted maps have no capacity, so without `std` the hint is ignored.
+    pub fn with_capacity(capacity: usize) -> Self {
+        #[cfg(feature = "std")]
+        return Map(Inner::with_capacity(capacity));
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = capacity;
+            Map(Inner::new())
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord + Hash,
+    {
+        self.0.get(key)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord + Hash,
+    {
+        self.0.get_mut(key)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord + Hash,
+    {
+        self.0.contains_key(key)
+    }
+
+    /// Replacing the value of an existing key keeps its position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes a key without disturbing the order of the remaining entries.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord + Hash,
+    {
+        #[cfg(feature = "preserve_order")]
+        return self.0.shift_remove(key);
+        #[cfg(not(feature = "preserve_order"))]
+        return self.0.remove(key);
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        Entry(self.0.entry(key))
+    }
+
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.0.retain(|k, v| f(k, v));
+    }
+
+    /// Puts the keys in ascending order. Only `preserve_order` maps keep an
+    /// order to change, so for the others this does nothing.
+    pub fn sort_keys(&mut self) {
+        #[cfg(feature = "preserve_order")]
+        self.0.sort_keys();
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.0.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut(self.0.iter_mut())
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.0.keys())
+    }
+
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.0.values())
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut(self.0.values_mut())
+    }
+}
+
+impl<K: Ord + Hash, V> Default for Map<K, V> {
+    fn default() -> Self {
+        Map::new()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for Map<K, V> {
+    fn clone(&self) -> Self {
+        Map(self.0.clone())
+    }
+}
+
+/// Maps are equal when they hold the same entries, in any order.
+impl<K: Ord + Hash, V: PartialEq> PartialEq for Map<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Map<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Panics if the key is missing.
+impl<K, V, Q> Index<&Q> for Map<K, V>
+where
+    K: Ord + Hash + Borrow<Q>,
+    Q: ?Sized + Ord + Hash,
+{
+    type Output = V;
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("Key is not in the map")
+    }
+}
+
+impl<K: Ord + Hash, V> FromIterator<(K, V)> for Map<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Map(iter.into_iter().collect())
+    }
+}
+
+impl<K: Ord + Hash, V> Extend<(K, V)> for Map<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<K, V> IntoIterator for Map<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter(self.0.into_iter())
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a Map<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Iter<'a, K, V> {
+        Iter(self.0.iter())
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut Map<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        IterMut(self.0.iter_mut())
+    }
+}
+
+/// A key's slot in a `Map`, from `Map::entry`.
+pub struct Entry<'a, K, V>(imp::Entry<'a, K, V>);
+
+impl<'a, K: Ord + Hash, V> Entry<'a, K, V> {
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.0.or_insert(default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        self.0.or_insert_with(default)
+    }
+}
+
+macro_rules! iterator {
+    ($(#[$doc:meta])* $name:ident<$($lt:lifetime)?> => $item:ty) => {
+        $(#[$doc])*
+        pub struct $name<$($lt,)? K, V>(imp::$name<$($lt,)? K, V>);
+
+        impl<$($lt,)? K, V> Iterator for $name<$($lt,)? K, V> {
+            type Item = $item;
+            fn next(&mut self) -> Option<$item> {
+                self.0.next()
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl<$($lt,)? K, V> ExactSizeIterator for $name<$($lt,)? K, V> {}
+    };
+}
+
+iterator!(
+    /// The entries of a `Map`, from `Map::iter`.
+    Iter<'a> => (&'a K, &'a V)
+);
+iterator!(
+    /// The entries of a `Map` with mutable values, from `Map::iter_mut`.
+    IterMut<'a> => (&'a K, &'a mut V)
+);
+iterator!(
+    /// The entries of a `Map`, by value.
+    IntoIter<> => (K, V)
+);
+iterator!(
+    /// The keys of a `Map`, from `Map::keys`.
+    Keys<'a> => &'a K
+);
+iterator!(
+    /// The values of a `Map`, from `Map::values`.
+    Values<'a> => &'a V
+);
+iterator!(
+    /// The mutable values of a `Map`, from `Map::values_mut`.
+    ValuesMut<'a> => &'a mut V
+);
+
+/// An object key, a plain `String` unless the `intern_keys` feature is on.
+#[cfg(not(feature = "intern_keys"))]
+pub type Key = String;
+#[cfg(feature = "intern_keys")]
+pub use crate::key::Key;
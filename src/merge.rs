@@ -0,0 +1,77 @@
+use crate::{JSONMap, JSONValue};
+
+/// How `deep_merge` combines two arrays found at the same position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrayMerge {
+    Replace,
+    Concat,
+}
+
+impl JSONValue {
+    pub fn merge_patch(&mut self, patch: &JSONValue) {
+        let patch = match patch {
+            JSONValue::Object(patch) => patch,
+            _ => {
+                *self = patch.clone();
+                return;
+            }
+        };
+        if !self.is_object() {
+            *self = JSONValue::Object(JSONMap::new());
+        }
+        if let JSONValue::Object(target) = self {
+            for (key, value) in patch {
+                if value.is_null() {
+                    target.remove(key);
+                } else {
+                    target
+                        .entry(key.clone())
+                        .or_insert(JSONValue::Null)
+                        .merge_patch(value);
+                }
+            }
+        }
+    }
+
+    /// Overlapping object keys are merged recursively; any other value from
+    /// `other`, including null, replaces the value in `self`. Arrays follow
+    /// `arrays`.
+    pub fn deep_merge(&mut self, other: JSONValue, arrays: ArrayMerge) {
+        match (self, other) {
+            (JSONValue::Object(target), JSONValue::Object(other)) => {
+                for (key, value) in other {
+                    match target.get_mut(&key) {
+                        Some(existing) => existing.deep_merge(value, arrays),
+                        None => {
+                            target.insert(key, value);
+                        }
+                    }
+                }
+            }
+            (JSONValue::Array(target), JSONValue::Array(other)) if arrays == ArrayMerge::Concat => {
+                target.extend(other);
+            }
+            (target, other) => *target = other,
+        }
+    }
+
+    /// For each object in `other`, replaces the element of `self` whose `key`
+    /// field equals its own, or appends it if there is none. Elements of
+    /// `other` without the key are always appended, and elements of `self`
+    /// without it are never replaced. Does nothing unless both are arrays.
+    pub fn merge_arrays_by_key(&mut self, other: &JSONValue, key: &str) {
+        let (target, other) = match (self, other) {
+            (JSONValue::Array(target), JSONValue::Array(other)) => (target, other),
+            _ => return,
+        };
+        for element in other {
+            let existing = element
+                .get(key)
+                .and_then(|id| target.iter_mut().find(|t| t.get(key) == Some(id)));
+            match existing {
+                Some(existing) => *existing = element.clone(),
+                None => target.push(element.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{JSONValue, ParseError, JSON};
+use core::fmt;
+use core::str::FromStr;
+
+/// The text of a number exactly as written, such as `1.10`. It is checked to
+/// be a valid JSON number with a finite value when created, so it can always
+/// be written out unchanged.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Number(String);
+
+impl Number {
+    // Only for literals the parser has already checked.
+    pub(crate) fn from_literal(literal: &str) -> Self {
+        Number(literal.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.0.parse().expect("Number literals are valid f64s")
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+/// Accepts exactly one number literal, without surrounding whitespace.
+impl FromStr for Number {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = JSON::new(s);
+        // A byte order mark is not part of a number.
+        parser.start = 0;
+        parser.i = 0;
+        parser.config.preserve_number_literals = true;
+        match parser.parse_number()? {
+            Some(JSONValue::RawNumber(number)) if parser.at_end() => Ok(number),
+            Some(_) => Err(parser.error(
+                ParseError::ExpectedEndOfInput,
+                format!("Unexpected trailing '{}'", &s[parser.i..]),
+            )),
+            None => Err(parser.error(ParseError::ExpectedDigit, "Expected a number".to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<Number> for JSONValue {
+    fn from(number: Number) -> Self {
+        JSONValue::RawNumber(number)
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{JSONMap, JSONValue, Key};
+use core::cmp::Ordering;
+
+/// Values of different types order as null < false < true < number < string <
+/// array < object. Numbers compare numerically, as f64 unless both are
+/// integers. Numbers that are equal but not `==`, such as `Integer(1)` and
+/// `Number(1.0)`, order integers first, then floats, then preserved literals
+/// by their text. Strings compare by code point, arrays element by element and
+/// objects by their entries sorted by key. NaN is unordered against every other
+/// number.
+impl PartialOrd for JSONValue {
+    fn partial_cmp(&self, other: &JSONValue) -> Option<Ordering> {
+        match (self, other) {
+            (JSONValue::Integer(a), JSONValue::Integer(b)) => a.partial_cmp(b),
+            (a, b) if a.is_number() && b.is_number() => {
+                let (a, b) = (number_key(a), number_key(b));
+                a.0.partial_cmp(&b.0)
+                    .map(|o| o.then(a.1.cmp(&b.1)).then_with(|| a.2.cmp(b.2)))
+            }
+            (JSONValue::String(a), JSONValue::String(b)) => a.partial_cmp(b),
+            (JSONValue::Array(a), JSONValue::Array(b)) => a.partial_cmp(b),
+            (JSONValue::Object(a), JSONValue::Object(b)) => sorted(a).partial_cmp(&sorted(b)),
+            _ => rank(self).partial_cmp(&rank(other)),
+        }
+    }
+}
+
+fn number_key(value: &JSONValue) -> (f64, u8, &str) {
+    match value {
+        JSONValue::Integer(n) => (*n as f64, 0, ""),
+        JSONValue::Number(n) => (*n, 1, ""),
+        JSONValue::RawNumber(n) => (n.as_f64(), 2, n.as_str()),
+        _ => unreachable!("Not a number: {}", value.type_name()),
+    }
+}
+
+fn rank(value: &JSONValue) -> u8 {
+    match value {
+        JSONValue::Null => 0,
+        JSONValue::False => 1,
+        JSONValue::True => 2,
+        JSONValue::Number(_) | JSONValue::Integer(_) | JSONValue::RawNumber(_) => 3,
+        JSONValue::String(_) => 4,
+        JSONValue::Array(_) => 5,
+        JSONValue::Object(_) => 6,
+    }
+}
+
+fn sorted(map: &JSONMap) -> Vec<(&Key, &JSONValue)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
@@ -0,0 +1,227 @@
+use crate::pointer::{escape_token, parse_index, parse_pointer, resolve_mut};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{JSONValue, Key};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+#[derive(Debug, PartialEq)]
+pub enum PatchError {
+    InvalidOperation(String),
+    PathNotFound(String),
+    TestFailed(String),
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatchError::InvalidOperation(msg) => write!(f, "Invalid operation: {}", msg),
+            PatchError::PathNotFound(msg) => write!(f, "Path not found: {}", msg),
+            PatchError::TestFailed(msg) => write!(f, "Test failed: {}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for PatchError {}
+
+fn member<'a>(op: &'a JSONValue, name: &str) -> Result<&'a JSONValue, PatchError> {
+    op.get(name).ok_or_else(|| {
+        PatchError::InvalidOperation(format!("Operation is missing the '{}' member", name))
+    })
+}
+
+fn member_str<'a>(op: &'a JSONValue, name: &str) -> Result<&'a str, PatchError> {
+    member(op, name)?.as_str().ok_or_else(|| {
+        PatchError::InvalidOperation(format!("Operation member '{}' must be a string", name))
+    })
+}
+
+fn tokens(path: &str) -> Result<Vec<String>, PatchError> {
+    parse_pointer(path)
+        .ok_or_else(|| PatchError::InvalidOperation(format!("Invalid JSON Pointer '{}'", path)))
+}
+
+fn not_found(path: &str) -> PatchError {
+    PatchError::PathNotFound(format!("'{}'", path))
+}
+
+// Equality as RFC 6902 defines it for `test`: numbers compare by value, so
+// `1` equals `1.0`, while arrays and objects compare member by member.
+fn json_eq(a: &JSONValue, b: &JSONValue) -> bool {
+    match (a, b) {
+        (JSONValue::Array(a), JSONValue::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_eq(a, b))
+        }
+        (JSONValue::Object(a), JSONValue::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| json_eq(a, b)))
+        }
+        _ if a.is_number() && b.is_number() => a.as_f64() == b.as_f64(),
+        _ => a == b,
+    }
+}
+
+fn add(doc: &mut JSONValue, path: &str, value: JSONValue) -> Result<(), PatchError> {
+    let mut tokens = tokens(path)?;
+    let last = match tokens.pop() {
+        Some(last) => last,
+        None => {
+            *doc = value;
+            return Ok(());
+        }
+    };
+    let target = resolve_mut(doc, &tokens).ok_or_else(|| not_found(path))?;
+    match target {
+        JSONValue::Object(_) => {
+            target.insert(last, value);
+        }
+        JSONValue::Array(a) => {
+            if last == "-" {
+                a.push(value);
+            } else {
+                match parse_index(&last) {
+                    Some(i) if i <= a.len() => a.insert(i, value),
+                    _ => return Err(not_found(path)),
+                }
+            }
+        }
+        _ => return Err(not_found(path)),
+    }
+    Ok(())
+}
+
+fn remove(doc: &mut JSONValue, path: &str) -> Result<JSONValue, PatchError> {
+    let mut tokens = tokens(path)?;
+    let last = tokens.pop().ok_or_else(|| {
+        PatchError::InvalidOperation("Cannot remove the document root".to_string())
+    })?;
+    match resolve_mut(doc, &tokens).ok_or_else(|| not_found(path))? {
+        JSONValue::Object(o) => o.remove(last.as_str()).ok_or_else(|| not_found(path)),
+        JSONValue::Array(a) => match parse_index(&last) {
+            Some(i) if i < a.len() => Ok(a.remove(i)),
+            _ => Err(not_found(path)),
+        },
+        _ => Err(not_found(path)),
+    }
+}
+
+fn apply_operation(doc: &mut JSONValue, op: &JSONValue) -> Result<(), PatchError> {
+    let path = member_str(op, "path")?;
+    match member_str(op, "op")? {
+        "add" => add(doc, path, member(op, "value")?.clone()),
+        "remove" => remove(doc, path).map(|_| ()),
+        "replace" => {
+            let value = member(op, "value")?.clone();
+            // Not `pointer_mut`, which would append an element for `-`.
+            *resolve_mut(doc, &tokens(path)?).ok_or_else(|| not_found(path))? = value;
+            Ok(())
+        }
+        "move" => {
+            let from = member_str(op, "from")?;
+            if path.starts_with(from) && path[from.len()..].starts_with('/') {
+                return Err(PatchError::InvalidOperation(format!(
+                    "Cannot move '{}' into its own child '{}'",
+                    from, path
+                )));
+            }
+            let value = remove(doc, from)?;
+            add(doc, path, value)
+        }
+        "copy" => {
+            let from = member_str(op, "from")?;
+            let value = doc.pointer(from).ok_or_else(|| not_found(from))?.clone();
+            add(doc, path, value)
+        }
+        "test" => {
+            let expected = member(op, "value")?;
+            match doc.pointer(path) {
+                Some(actual) if json_eq(actual, expected) => Ok(()),
+                Some(actual) => Err(PatchError::TestFailed(format!(
+                    "Expected {} at '{}', found {}",
+                    expected, path, actual
+                ))),
+                None => Err(not_found(path)),
+            }
+        }
+        other => Err(PatchError::InvalidOperation(format!(
+            "Unknown operation '{}'",
+            other
+        ))),
+    }
+}
+
+impl JSONValue {
+    /// Operations are applied to a copy, so a failing operation leaves `self`
+    /// untouched.
+    pub fn apply_patch(&mut self, ops: &JSONValue) -> Result<(), PatchError> {
+        let ops = ops.as_array().ok_or_else(|| {
+            PatchError::InvalidOperation("A patch must be an array of operations".to_string())
+        })?;
+        let mut doc = self.clone();
+        for op in ops {
+            if !op.is_object() {
+                return Err(PatchError::InvalidOperation(
+                    "Each operation must be an object".to_string(),
+                ));
+            }
+            apply_operation(&mut doc, op)?;
+        }
+        *self = doc;
+        Ok(())
+    }
+}
+
+/// Returns a JSON Patch that turns `a` into `b` when applied with
+/// `apply_patch`. Object members are compared key by key and array elements
+/// index by index; anything else that differs is replaced whole.
+pub fn diff(a: &JSONValue, b: &JSONValue) -> JSONValue {
+    let mut ops = vec![];
+    diff_into(a, b, "", &mut ops);
+    JSONValue::Array(ops)
+}
+
+fn operation(op: &str, path: &str, value: Option<&JSONValue>) -> JSONValue {
+    let mut result = json!({"op": op, "path": path});
+    if let Some(value) = value {
+        result.insert("value", value.clone());
+    }
+    result
+}
+
+fn diff_into(a: &JSONValue, b: &JSONValue, path: &str, ops: &mut Vec<JSONValue>) {
+    if a == b {
+        return;
+    }
+    match (a, b) {
+        (JSONValue::Object(from), JSONValue::Object(to)) => {
+            let mut keys: Vec<&Key> = from.keys().chain(to.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = format!("{}/{}", path, escape_token(key));
+                match (from.get(key), to.get(key)) {
+                    (Some(old), Some(new)) => diff_into(old, new, &child, ops),
+                    (Some(_), None) => ops.push(operation("remove", &child, None)),
+                    (None, Some(new)) => ops.push(operation("add", &child, Some(new))),
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+        (JSONValue::Array(from), JSONValue::Array(to)) => {
+            for (i, (old, new)) in from.iter().zip(to).enumerate() {
+                diff_into(old, new, &format!("{}/{}", path, i), ops);
+            }
+            for (i, new) in to.iter().enumerate().skip(from.len()) {
+                ops.push(operation("add", &format!("{}/{}", path, i), Some(new)));
+            }
+            // Remove from the end so earlier indices stay valid.
+            for i in (to.len()..from.len()).rev() {
+                ops.push(operation("remove", &format!("{}/{}", path, i), None));
+            }
+        }
+        _ => ops.push(operation("replace", path, Some(b))),
+    }
+}
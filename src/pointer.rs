@@ -0,0 +1,236 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{JSONMap, JSONValue};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+#[derive(Debug, PartialEq)]
+pub enum PathError {
+    InvalidPointer(String),
+    /// The path runs through a string, number, boolean or null.
+    NotAContainer(String),
+    IndexOutOfBounds(String),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathError::InvalidPointer(msg) => write!(f, "Invalid pointer: {}", msg),
+            PathError::NotAContainer(msg) => write!(f, "Not a container: {}", msg),
+            PathError::IndexOutOfBounds(msg) => write!(f, "Index out of bounds: {}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for PathError {}
+
+// Splits an RFC 6901 pointer into unescaped reference tokens. Returns `None`
+// for pointers that don't start with `/` or contain an invalid `~` escape.
+pub(crate) fn parse_pointer(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(vec![]);
+    }
+    if !pointer.starts_with('/') {
+        return None;
+    }
+    pointer[1..].split('/').map(unescape_token).collect()
+}
+
+pub(crate) fn escape_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape_token(token: &str) -> Option<String> {
+    let mut result = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '~' {
+            match chars.next() {
+                Some('0') => result.push('~'),
+                Some('1') => result.push('/'),
+                _ => return None,
+            }
+        } else {
+            result.push(ch);
+        }
+    }
+    Some(result)
+}
+
+// Array indices are decimal with no leading zeros.
+pub(crate) fn parse_index(token: &str) -> Option<usize> {
+    if token.is_empty()
+        || (token.len() > 1 && token.starts_with('0'))
+        || !token.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    token.parse().ok()
+}
+
+pub(crate) fn resolve_mut<'a>(
+    mut target: &'a mut JSONValue,
+    tokens: &[String],
+) -> Option<&'a mut JSONValue> {
+    for token in tokens {
+        target = match target {
+            JSONValue::Object(o) => o.get_mut(token.as_str())?,
+            JSONValue::Array(a) => a.get_mut(parse_index(token)?)?,
+            _ => return None,
+        };
+    }
+    Some(target)
+}
+
+impl JSONValue {
+    pub fn pointer(&self, pointer: &str) -> Option<&JSONValue> {
+        let mut target = self;
+        for token in parse_pointer(pointer)? {
+            target = match target {
+                JSONValue::Object(o) => o.get(token.as_str())?,
+                JSONValue::Array(a) => a.get(parse_index(&token)?)?,
+                _ => return None,
+            };
+        }
+        Some(target)
+    }
+
+    /// A final `-` token addresses the position past the end of an array, as
+    /// in JSON Patch: a null element is appended there and returned.
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut JSONValue> {
+        let tokens = parse_pointer(pointer)?;
+        match tokens.split_last() {
+            Some((last, parent)) if last == "-" => match resolve_mut(self, parent)? {
+                JSONValue::Array(a) => {
+                    a.push(JSONValue::Null);
+                    a.last_mut()
+                }
+                JSONValue::Object(o) => o.get_mut("-"),
+                _ => None,
+            },
+            _ => resolve_mut(self, &tokens),
+        }
+    }
+
+    /// Stores `value` at `pointer`, creating missing parents on the way: an
+    /// array when the next token is an index or `-`, otherwise an object. An
+    /// array index may address an existing element or the end of the array.
+    pub fn set_path(&mut self, pointer: &str, value: JSONValue) -> Result<(), PathError> {
+        let tokens = parse_pointer(pointer)
+            .ok_or_else(|| PathError::InvalidPointer(format!("'{}'", pointer)))?;
+        let mut target = self;
+        for (n, token) in tokens.iter().enumerate() {
+            let child = match tokens.get(n + 1) {
+                Some(next) if next == "-" || parse_index(next).is_some() => {
+                    JSONValue::Array(vec![])
+                }
+                Some(_) => JSONValue::Object(JSONMap::new()),
+                None => JSONValue::Null,
+            };
+            target = match target {
+                JSONValue::Object(o) => o.entry(token.into()).or_insert(child),
+                JSONValue::Array(a) => {
+                    let index = if token == "-" {
+                        a.len()
+                    } else {
+                        parse_index(token).ok_or_else(|| {
+                            PathError::InvalidPointer(format!(
+                                "'{}' is not an array index in '{}'",
+                                token, pointer
+                            ))
+                        })?
+                    };
+                    if index == a.len() {
+                        a.push(child);
+                    }
+                    a.get_mut(index).ok_or_else(|| {
+                        PathError::IndexOutOfBounds(format!("{} in '{}'", index, pointer))
+                    })?
+                }
+                _ => return Err(PathError::NotAContainer(format!("'{}'", pointer))),
+            };
+        }
+        *target = value;
+        Ok(())
+    }
+
+    /// Removes and returns the value at `pointer`. The root can't be deleted.
+    pub fn delete_path(&mut self, pointer: &str) -> Option<JSONValue> {
+        let mut tokens = parse_pointer(pointer)?;
+        let last = tokens.pop()?;
+        match resolve_mut(self, &tokens)? {
+            JSONValue::Object(o) => o.remove(last.as_str()),
+            JSONValue::Array(a) => {
+                let index = parse_index(&last).filter(|&i| i < a.len())?;
+                Some(a.remove(index))
+            }
+            _ => None,
+        }
+    }
+
+    /// Calls `f` with the JSON Pointer and value of every node, parents before
+    /// their children, starting with the root at "".
+    pub fn visit<F: FnMut(&str, &JSONValue)>(&self, mut f: F) {
+        visit_node(self, &mut String::new(), &mut f);
+    }
+
+    /// Like `visit`, but `f` may modify each node before its children (if it
+    /// still has any) are visited.
+    pub fn visit_mut<F: FnMut(&str, &mut JSONValue)>(&mut self, mut f: F) {
+        visit_node_mut(self, &mut String::new(), &mut f);
+    }
+}
+
+fn visit_node<F: FnMut(&str, &JSONValue)>(value: &JSONValue, path: &mut String, f: &mut F) {
+    f(path, value);
+    let len = path.len();
+    match value {
+        JSONValue::Object(o) => {
+            for (key, child) in o {
+                path.push('/');
+                path.push_str(&escape_token(key));
+                visit_node(child, path, f);
+                path.truncate(len);
+            }
+        }
+        JSONValue::Array(a) => {
+            for (i, child) in a.iter().enumerate() {
+                path.push('/');
+                path.push_str(&i.to_string());
+                visit_node(child, path, f);
+                path.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn visit_node_mut<F: FnMut(&str, &mut JSONValue)>(
+    value: &mut JSONValue,
+    path: &mut String,
+    f: &mut F,
+) {
+    f(path, value);
+    let len = path.len();
+    match value {
+        JSONValue::Object(o) => {
+            for (key, child) in o.iter_mut() {
+                path.push('/');
+                path.push_str(&escape_token(key));
+                visit_node_mut(child, path, f);
+                path.truncate(len);
+            }
+        }
+        JSONValue::Array(a) => {
+            for (i, child) in a.iter_mut().enumerate() {
+                path.push('/');
+                path.push_str(&i.to_string());
+                visit_node_mut(child, path, f);
+                path.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::JSONValue;
+
+enum Selector {
+    Key(String),
+    // Negative indices count back from the end of the array.
+    Index(i64),
+    Wildcard,
+}
+
+// One step of a path. `descendants` is set for `..`, which applies the
+// selector to the current nodes and everything below them.
+struct Segment {
+    descendants: bool,
+    selector: Selector,
+}
+
+// Parses the supported JSONPath subset: `$` followed by any mix of `.key`,
+// `.*`, `['key']`, `["key"]`, `[index]`, `[*]` and `..` before any of those.
+fn parse_path(path: &str) -> Option<Vec<Segment>> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut segments = vec![];
+    while !rest.is_empty() {
+        let descendants = rest.starts_with("..");
+        if descendants {
+            rest = &rest[2..];
+        } else if let Some(stripped) = rest.strip_prefix('.') {
+            rest = stripped;
+        } else if !rest.starts_with('[') {
+            return None;
+        }
+        let selector;
+        if let Some(bracket) = rest.strip_prefix('[') {
+            let end = bracketed_end(bracket)?;
+            selector = parse_bracketed(bracket[..end].trim())?;
+            rest = &bracket[end + 1..];
+        } else {
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            selector = match &rest[..end] {
+                "" => return None,
+                "*" => Selector::Wildcard,
+                name => Selector::Key(name.to_string()),
+            };
+            rest = &rest[end..];
+        }
+        segments.push(Segment {
+            descendants,
+            selector,
+        });
+    }
+    Some(segments)
+}
+
+// The offset of the `]` closing a bracket, skipping over quoted keys.
+fn bracketed_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, ch) in s.char_indices() {
+        match (quote, ch) {
+            (None, '\'') | (None, '"') => quote = Some(ch),
+            (Some(q), _) if ch == q => quote = None,
+            (None, ']') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_bracketed(inner: &str) -> Option<Selector> {
+    if inner == "*" {
+        return Some(Selector::Wildcard);
+    }
+    for quote in ['\'', '"'] {
+        if let Some(key) = inner
+            .strip_prefix(quote)
+            .and_then(|key| key.strip_suffix(quote))
+        {
+            return Some(Selector::Key(key.to_string()));
+        }
+    }
+    inner.parse().ok().map(Selector::Index)
+}
+
+fn collect_descendants<'a>(value: &'a JSONValue, out: &mut Vec<&'a JSONValue>) {
+    out.push(value);
+    for child in children(value) {
+        collect_descendants(child, out);
+    }
+}
+
+fn children(value: &JSONValue) -> Box<dyn Iterator<Item = &JSONValue> + '_> {
+    match value {
+        JSONValue::Object(o) => Box::new(o.values()),
+        JSONValue::Array(a) => Box::new(a.iter()),
+        _ => Box::new(core::iter::empty()),
+    }
+}
+
+fn select<'a>(value: &'a JSONValue, selector: &Selector, out: &mut Vec<&'a JSONValue>) {
+    match (selector, value) {
+        (Selector::Key(key), JSONValue::Object(o)) => out.extend(o.get(key.as_str())),
+        (Selector::Index(i), JSONValue::Array(a)) => {
+            let index = if *i < 0 { a.len() as i64 + i } else { *i };
+            if index >= 0 {
+                out.extend(a.get(index as usize));
+            }
+        }
+        (Selector::Wildcard, _) => out.extend(children(value)),
+        _ => {}
+    }
+}
+
+impl JSONValue {
+    /// Selects every node matching a JSONPath expression such as
+    /// `$..book[*].price`. Returns an empty list if nothing matches or the
+    /// path is not in the supported subset.
+    pub fn query(&self, path: &str) -> Vec<&JSONValue> {
+        let segments = match parse_path(path) {
+            Some(segments) => segments,
+            None => return vec![],
+        };
+        let mut current = vec![self];
+        for segment in &segments {
+            if segment.descendants {
+                let mut expanded = vec![];
+                for value in current {
+                    collect_descendants(value, &mut expanded);
+                }
+                current = expanded;
+            }
+            let mut next = vec![];
+            for value in current {
+                select(value, &segment.selector, &mut next);
+            }
+            current = next;
+        }
+        current
+    }
+}
@@ -0,0 +1,157 @@
+use crate::pointer::escape_token;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::JSONValue;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+
+/// A failed schema check, with the JSON Pointer of the offending value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaError {
+    pub pointer: String,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Schema violation at '{}': {}",
+            self.pointer, self.message
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for SchemaError {}
+
+fn is_integer(value: &JSONValue) -> bool {
+    value.as_f64().is_some_and(|n| n % 1.0 == 0.0)
+}
+
+fn has_type(value: &JSONValue, name: &str) -> bool {
+    match name {
+        "integer" => is_integer(value),
+        name => value.type_name() == name,
+    }
+}
+
+struct Validator {
+    errors: Vec<SchemaError>,
+}
+
+impl Validator {
+    fn fail(&mut self, pointer: &str, message: String) {
+        self.errors.push(SchemaError {
+            pointer: pointer.to_string(),
+            message,
+        });
+    }
+
+    fn check(&mut self, value: &JSONValue, schema: &JSONValue, pointer: &str) {
+        match schema {
+            JSONValue::True => return,
+            JSONValue::False => return self.fail(pointer, "No value is allowed".to_string()),
+            _ => {}
+        }
+        match schema.get("type") {
+            Some(JSONValue::String(name)) if !has_type(value, name) => self.fail(
+                pointer,
+                format!("Expected {}, found {}", name, value.type_name()),
+            ),
+            Some(JSONValue::Array(names)) => {
+                let names: Vec<_> = names.iter().filter_map(JSONValue::as_str).collect();
+                if !names.iter().any(|name| has_type(value, name)) {
+                    self.fail(
+                        pointer,
+                        format!(
+                            "Expected one of {}, found {}",
+                            names.join(", "),
+                            value.type_name()
+                        ),
+                    );
+                }
+            }
+            _ => {}
+        }
+        if let Some(JSONValue::Array(options)) = schema.get("enum") {
+            if !options.iter().any(|option| option.approx_eq(value, 0.0)) {
+                self.fail(
+                    pointer,
+                    format!("{} is not one of the allowed values", value),
+                );
+            }
+        }
+        match value {
+            JSONValue::Object(o) => {
+                if let Some(JSONValue::Array(required)) = schema.get("required") {
+                    for key in required.iter().filter_map(JSONValue::as_str) {
+                        if !o.contains_key(key) {
+                            self.fail(pointer, format!("Missing required property '{}'", key));
+                        }
+                    }
+                }
+                if let Some(JSONValue::Object(properties)) = schema.get("properties") {
+                    for (key, child) in o {
+                        if let Some(subschema) = properties.get(key) {
+                            let child_pointer = format!("{}/{}", pointer, escape_token(key));
+                            self.check(child, subschema, &child_pointer);
+                        }
+                    }
+                }
+            }
+            JSONValue::Array(a) => {
+                if let Some(items) = schema.get("items") {
+                    for (i, child) in a.iter().enumerate() {
+                        self.check(child, items, &format!("{}/{}", pointer, i));
+                    }
+                }
+            }
+            JSONValue::String(s) => {
+                let len = s.chars().count();
+                if let Some(min) = schema.get("minLength").and_then(JSONValue::as_u64) {
+                    if (len as u64) < min {
+                        self.fail(pointer, format!("String is shorter than {}", min));
+                    }
+                }
+                if let Some(max) = schema.get("maxLength").and_then(JSONValue::as_u64) {
+                    if len as u64 > max {
+                        self.fail(pointer, format!("String is longer than {}", max));
+                    }
+                }
+            }
+            value => {
+                if let Some(n) = value.as_f64() {
+                    if let Some(min) = schema.get("minimum").and_then(JSONValue::as_f64) {
+                        if n < min {
+                            self.fail(pointer, format!("{} is less than {}", value, min));
+                        }
+                    }
+                    if let Some(max) = schema.get("maximum").and_then(JSONValue::as_f64) {
+                        if n > max {
+                            self.fail(pointer, format!("{} is greater than {}", value, max));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl JSONValue {
+    /// Checks the value against a JSON Schema, supporting only `type`,
+    /// `required`, `properties`, `items` (a single schema for every element),
+    /// `minimum`, `maximum`, `minLength`, `maxLength` and `enum`, plus the
+    /// `true` and `false` schemas. Other keywords, and keywords whose values
+    /// have the wrong type, are ignored. Every violation is reported.
+    pub fn validate_schema(&self, schema: &JSONValue) -> Result<(), Vec<SchemaError>> {
+        let mut validator = Validator { errors: vec![] };
+        validator.check(self, schema, "");
+        if validator.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validator.errors)
+        }
+    }
+}
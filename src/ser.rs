@@ -0,0 +1,414 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Indent, JSONValue, ParseError, SerializerConfig, JSON};
+use core::fmt::{self, Write};
+#[cfg(feature = "std")]
+use std::io;
+
+/// Writes `value` as compact JSON. Floats use the shortest representation
+/// that parses back to the same value and keep a trailing `.0` when integral;
+/// NaN and infinities become `null`.
+pub fn stringify(value: &JSONValue) -> String {
+    value.to_string_with_config(&SerializerConfig::default())
+}
+
+/// Parses `json` and pretty-prints it with `indent` spaces per level.
+pub fn prettify(json: &str, indent: usize) -> Result<String, ParseError> {
+    Ok(JSON::parse(json)?.to_string_pretty(indent))
+}
+
+/// Escapes `s` for use inside a JSON string literal, without the surrounding
+/// quotes.
+pub fn escape_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    write_escaped(&mut out, s, false).expect("Writing to a String cannot fail");
+    out
+}
+
+/// Decodes the contents of a JSON string literal given without its quotes. An
+/// unescaped quote is an error. Error columns count the implied opening quote.
+pub fn unescape_string(s: &str) -> Result<String, ParseError> {
+    let quoted = format!("\"{}\"", s);
+    let mut parser = JSON::new(&quoted);
+    let value = parser.parse_str()?.expect("Input starts with a quote");
+    parser.expect_end()?;
+    Ok(value.into_owned())
+}
+
+/// Removes insignificant whitespace from a JSON document. Key order, number
+/// literals and string escapes are kept exactly as written. A leading byte
+/// order mark is dropped.
+pub fn minify(json: &str) -> Result<String, ParseError> {
+    JSON::validate(json)?;
+    let json = json.strip_prefix('\u{FEFF}').unwrap_or(json);
+    let mut out = String::with_capacity(json.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for ch in json.chars() {
+        if in_string {
+            out.push(ch);
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+        } else if !ch.is_ascii_whitespace() {
+            in_string = ch == '"';
+            out.push(ch);
+        }
+    }
+    Ok(out)
+}
+
+/// Writes the same compact JSON as `stringify`.
+impl fmt::Display for JSONValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_value(f, self, &SerializerConfig::default())
+    }
+}
+
+impl JSONValue {
+    /// Object keys are ordered by their UTF-16 code units at every level, so
+    /// equal values always produce the same text.
+    pub fn to_string_sorted(&self) -> String {
+        self.to_string_with_config(&SerializerConfig::new().sort_keys(true))
+    }
+
+    /// The RFC 8785 (JCS) canonical form: sorted keys, no whitespace, minimal
+    /// string escapes and ECMAScript number formatting. Integers are formatted
+    /// as the double they convert to. JCS has no representation for NaN or
+    /// Infinity, so they are written as null.
+    pub fn to_canonical_string(&self) -> String {
+        let config = SerializerConfig {
+            canonical: true,
+            ..SerializerConfig::new().sort_keys(true)
+        };
+        self.to_string_with_config(&config)
+    }
+
+    pub fn to_string_pretty(&self, indent: usize) -> String {
+        self.to_string_pretty_with(Indent::Spaces(indent))
+    }
+
+    pub fn to_string_pretty_with(&self, indent: Indent) -> String {
+        self.to_string_with_config(&SerializerConfig::new().indent(indent))
+    }
+
+    pub fn to_string_with_config(&self, config: &SerializerConfig) -> String {
+        let mut out = String::new();
+        write_value(&mut out, self, config).expect("Writing to a String cannot fail");
+        out
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_writer<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        self.to_writer_with_config(w, &SerializerConfig::default())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_writer_pretty<W: io::Write>(&self, w: &mut W, indent: usize) -> io::Result<()> {
+        self.to_writer_with_config(w, &SerializerConfig::new().indent(Indent::Spaces(indent)))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_writer_with_config<W: io::Write>(
+        &self,
+        w: &mut W,
+        config: &SerializerConfig,
+    ) -> io::Result<()> {
+        write_io(w, |adapter| write_value(adapter, self, config))
+    }
+}
+
+/// Writes a JSON array one element at a time, so large exports never need the
+/// whole array in memory. Elements are written compactly.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ArrayWriter<W: io::Write> {
+    inner: W,
+    config: SerializerConfig,
+    started: bool,
+    empty: bool,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> ArrayWriter<W> {
+    pub fn new(inner: W) -> Self {
+        ArrayWriter::with_config(inner, &SerializerConfig::default())
+    }
+
+    /// Indentation is ignored; the other settings apply to every element.
+    pub fn with_config(inner: W, config: &SerializerConfig) -> Self {
+        ArrayWriter {
+            inner,
+            config: SerializerConfig {
+                indent: None,
+                ..config.clone()
+            },
+            started: false,
+            empty: true,
+        }
+    }
+
+    /// Writes the opening bracket. Called automatically by the first
+    /// `write_element` or `finish` if needed.
+    pub fn begin(&mut self) -> io::Result<()> {
+        if !self.started {
+            self.inner.write_all(b"[")?;
+            self.started = true;
+        }
+        Ok(())
+    }
+
+    pub fn write_element(&mut self, value: &JSONValue) -> io::Result<()> {
+        self.begin()?;
+        if !self.empty {
+            self.inner.write_all(b",")?;
+        }
+        self.empty = false;
+        let config = &self.config;
+        write_io(&mut self.inner, |adapter| {
+            write_value(adapter, value, config)
+        })
+    }
+
+    /// Closes the array and hands back the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.begin()?;
+        self.inner.write_all(b"]")?;
+        Ok(self.inner)
+    }
+}
+
+// Runs `f` against an adapter over `w`, surfacing any underlying I/O error.
+#[cfg(feature = "std")]
+fn write_io<W: io::Write, F: FnOnce(&mut IoAdapter<W>) -> fmt::Result>(
+    w: &mut W,
+    f: F,
+) -> io::Result<()> {
+    let mut adapter = IoAdapter {
+        inner: w,
+        error: None,
+    };
+    match f(&mut adapter) {
+        Ok(()) => Ok(()),
+        Err(fmt::Error) => Err(adapter
+            .error
+            .unwrap_or_else(|| io::Error::other("Formatting error"))),
+    }
+}
+
+// Bridges `fmt::Write` to `io::Write`, keeping the underlying I/O error since
+// `fmt::Error` carries no detail.
+#[cfg(feature = "std")]
+struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+pub(crate) fn write_value<W: Write>(
+    w: &mut W,
+    value: &JSONValue,
+    opts: &SerializerConfig,
+) -> fmt::Result {
+    write_nested(w, value, opts, 0)
+}
+
+fn write_newline<W: Write>(w: &mut W, opts: &SerializerConfig, depth: usize) -> fmt::Result {
+    match opts.indent {
+        None => Ok(()),
+        Some(indent) => {
+            w.write_char('\n')?;
+            for _ in 0..depth {
+                match indent {
+                    Indent::Spaces(n) => {
+                        for _ in 0..n {
+                            w.write_char(' ')?;
+                        }
+                    }
+                    Indent::Tab => w.write_char('\t')?,
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn write_nested<W: Write>(
+    w: &mut W,
+    value: &JSONValue,
+    opts: &SerializerConfig,
+    depth: usize,
+) -> fmt::Result {
+    match value {
+        JSONValue::Object(map) if map.is_empty() => w.write_str("{}"),
+        JSONValue::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            if opts.sort_keys {
+                entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            }
+            w.write_char('{')?;
+            for (i, (key, val)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    w.write_char(',')?;
+                }
+                write_newline(w, opts, depth + 1)?;
+                write_string(w, key, opts.ascii_only)?;
+                w.write_char(':')?;
+                if opts.indent.is_some() {
+                    w.write_char(' ')?;
+                }
+                write_nested(w, val, opts, depth + 1)?;
+            }
+            write_newline(w, opts, depth)?;
+            w.write_char('}')
+        }
+        JSONValue::Array(arr) if arr.is_empty() => w.write_str("[]"),
+        JSONValue::Array(arr) => {
+            w.write_char('[')?;
+            for (i, val) in arr.iter().enumerate() {
+                if i > 0 {
+                    w.write_char(',')?;
+                }
+                write_newline(w, opts, depth + 1)?;
+                write_nested(w, val, opts, depth + 1)?;
+            }
+            write_newline(w, opts, depth)?;
+            w.write_char(']')
+        }
+        JSONValue::String(s) => write_string(w, s, opts.ascii_only),
+        JSONValue::Number(n) if opts.canonical => write_number_canonical(w, *n),
+        JSONValue::Integer(n) if opts.canonical => write_number_canonical(w, *n as f64),
+        JSONValue::RawNumber(n) if opts.canonical => write_number_canonical(w, n.as_f64()),
+        JSONValue::RawNumber(n) => w.write_str(n.as_str()),
+        JSONValue::Number(n) => {
+            let n = match opts.precision {
+                Some(digits) => round_significant(*n, digits),
+                None => *n,
+            };
+            write_number(w, n, opts.allow_non_finite)
+        }
+        JSONValue::Integer(n) => write!(w, "{}", n),
+        JSONValue::True => w.write_str("true"),
+        JSONValue::False => w.write_str("false"),
+        JSONValue::Null => w.write_str("null"),
+    }
+}
+
+// Control characters are always escaped; `ascii_only` additionally escapes
+// everything above 0x7F, using surrogate pairs for astral code points.
+pub(crate) fn write_string<W: Write>(w: &mut W, s: &str, ascii_only: bool) -> fmt::Result {
+    w.write_char('"')?;
+    write_escaped(w, s, ascii_only)?;
+    w.write_char('"')
+}
+
+fn write_escaped<W: Write>(w: &mut W, s: &str, ascii_only: bool) -> fmt::Result {
+    for ch in s.chars() {
+        match ch {
+            '"' => w.write_str("\\\"")?,
+            '\\' => w.write_str("\\\\")?,
+            '\u{8}' => w.write_str("\\b")?,
+            '\x0C' => w.write_str("\\f")?,
+            '\n' => w.write_str("\\n")?,
+            '\r' => w.write_str("\\r")?,
+            '\t' => w.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(w, "\\u{:04x}", c as u32)?,
+            c if ascii_only && !c.is_ascii() => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    write!(w, "\\u{:04x}", unit)?;
+                }
+            }
+            c => w.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+// Rounds through decimal scientific notation, which leaves non-finite
+// values unchanged.
+fn round_significant(n: f64, digits: usize) -> f64 {
+    format!("{:.*e}", digits - 1, n).parse().unwrap_or(n)
+}
+
+// Numbers use the shortest representation that round-trips. Integral values
+// keep a trailing `.0` so they parse back as `Number` rather than `Integer`.
+// Magnitudes outside [1e-7, 1e21) switch to exponent notation, matching
+// JavaScript. JSON has no NaN or Infinity, so those are written as null
+// unless `allow_non_finite` asks for the JavaScript literals.
+pub(crate) fn write_number<W: Write>(w: &mut W, n: f64, allow_non_finite: bool) -> fmt::Result {
+    if !n.is_finite() {
+        return match (allow_non_finite, n.is_nan(), n > 0.0) {
+            (false, _, _) => w.write_str("null"),
+            (true, true, _) => w.write_str("NaN"),
+            (true, false, true) => w.write_str("Infinity"),
+            (true, false, false) => w.write_str("-Infinity"),
+        };
+    }
+    let abs = if n < 0.0 { -n } else { n };
+    if abs != 0.0 && !(1e-7..1e21).contains(&abs) {
+        write!(w, "{:e}", n)
+    } else if n % 1.0 == 0.0 {
+        write!(w, "{}.0", n)
+    } else {
+        write!(w, "{}", n)
+    }
+}
+
+// ECMAScript's Number::toString, built from the shortest round-trip digits:
+// plain notation for exponents from -7 to 20 and `d.ddde+x` otherwise, with
+// no trailing `.0` and -0 written as 0.
+fn write_number_canonical<W: Write>(w: &mut W, n: f64) -> fmt::Result {
+    if !n.is_finite() {
+        return w.write_str("null");
+    }
+    if n == 0.0 {
+        return w.write_char('0');
+    }
+    if n < 0.0 {
+        w.write_char('-')?;
+    }
+    let scientific = format!("{:e}", if n < 0.0 { -n } else { n });
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("Exponent notation contains 'e'");
+    let digits: String = mantissa.chars().filter(|&ch| ch != '.').collect();
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().expect("Exponent is an integer") + 1;
+    if k <= n && n <= 21 {
+        w.write_str(&digits)?;
+        for _ in k..n {
+            w.write_char('0')?;
+        }
+        Ok(())
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        write!(w, "{}.{}", int, frac)
+    } else if -6 < n && n <= 0 {
+        w.write_str("0.")?;
+        for _ in n..0 {
+            w.write_char('0')?;
+        }
+        w.write_str(&digits)
+    } else {
+        let (first, rest) = digits.split_at(1);
+        w.write_str(first)?;
+        if !rest.is_empty() {
+            write!(w, ".{}", rest)?;
+        }
+        let sign = if n > 0 { '+' } else { '-' };
+        write!(w, "e{}{}", sign, (n - 1).abs())
+    }
+}
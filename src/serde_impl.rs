@@ -0,0 +1,104 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{JSONMap, JSONValue};
+use core::fmt;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+impl Serialize for JSONValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            JSONValue::Object(o) => {
+                let mut map = serializer.serialize_map(Some(o.len()))?;
+                for (key, value) in o {
+                    map.serialize_entry(key.as_str(), value)?;
+                }
+                map.end()
+            }
+            JSONValue::Array(a) => a.serialize(serializer),
+            JSONValue::String(s) => serializer.serialize_str(s),
+            JSONValue::Number(n) => serializer.serialize_f64(*n),
+            JSONValue::Integer(n) => serializer.serialize_i64(*n),
+            JSONValue::RawNumber(n) => {
+                if let Ok(i) = n.as_str().parse() {
+                    serializer.serialize_i64(i)
+                } else if let Ok(u) = n.as_str().parse() {
+                    serializer.serialize_u64(u)
+                } else {
+                    serializer.serialize_f64(n.as_f64())
+                }
+            }
+            JSONValue::True => serializer.serialize_bool(true),
+            JSONValue::False => serializer.serialize_bool(false),
+            JSONValue::Null => serializer.serialize_unit(),
+        }
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = JSONValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<JSONValue, E> {
+        Ok(JSONValue::from(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<JSONValue, E> {
+        Ok(JSONValue::Integer(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<JSONValue, E> {
+        Ok(JSONValue::from(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<JSONValue, E> {
+        Ok(JSONValue::Number(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<JSONValue, E> {
+        Ok(JSONValue::from(v))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<JSONValue, E> {
+        Ok(JSONValue::String(v))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<JSONValue, E> {
+        Ok(JSONValue::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<JSONValue, E> {
+        Ok(JSONValue::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<JSONValue, D::Error> {
+        JSONValue::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JSONValue, A::Error> {
+        let mut result = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            result.push(value);
+        }
+        Ok(JSONValue::Array(result))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JSONValue, A::Error> {
+        let mut result = JSONValue::Object(JSONMap::new());
+        while let Some((key, value)) = map.next_entry::<String, JSONValue>()? {
+            result.insert(key, value);
+        }
+        Ok(result)
+    }
+}
+
+impl<'de> Deserialize<'de> for JSONValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<JSONValue, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::JSONValue;
+use serde_json::{Number, Value};
+
+/// Non-finite numbers have no `serde_json` representation and become null, as
+/// they do when `serde_json` serializes an f64.
+impl From<JSONValue> for Value {
+    fn from(value: JSONValue) -> Value {
+        match value {
+            JSONValue::Object(o) => o.into_iter().collect(),
+            JSONValue::Array(a) => Value::Array(a.into_iter().map(Value::from).collect()),
+            JSONValue::String(s) => Value::String(s),
+            JSONValue::Number(n) => Number::from_f64(n).map_or(Value::Null, Value::Number),
+            JSONValue::Integer(n) => Value::Number(n.into()),
+            JSONValue::RawNumber(n) => n.as_str().parse().map_or(Value::Null, Value::Number),
+            JSONValue::True => Value::Bool(true),
+            JSONValue::False => Value::Bool(false),
+            JSONValue::Null => Value::Null,
+        }
+    }
+}
+
+/// Integers outside the i64 range become floats, as they do when parsing.
+impl From<Value> for JSONValue {
+    fn from(value: Value) -> JSONValue {
+        match value {
+            Value::Object(o) => o
+                .into_iter()
+                .map(|(k, v)| (k, JSONValue::from(v)))
+                .collect(),
+            Value::Array(a) => a.into_iter().map(JSONValue::from).collect(),
+            Value::String(s) => JSONValue::String(s),
+            Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    JSONValue::Integer(i)
+                } else if let Some(u) = n.as_u64() {
+                    JSONValue::from(u)
+                } else {
+                    JSONValue::Number(n.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            Value::Bool(b) => JSONValue::from(b),
+            Value::Null => JSONValue::Null,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::pointer::escape_token;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    Builder, DuplicateKeyPolicy, JSONArray, JSONMap, JSONValue, ParseError, ParserConfig,
+    ValueBuilder, JSON,
+};
+use alloc::borrow::Cow;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Byte offsets of a value in the input, from its first character to just
+/// past its last.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+// Builds owned values like `ValueBuilder` while recording the span of each
+// under its pointer.
+struct SpanBuilder {
+    values: ValueBuilder,
+    spans: HashMap<String, Span>,
+    pointer: String,
+    // The pointer length and `discarding` flag to restore after each child.
+    parents: Vec<(usize, bool)>,
+    // Set inside a value that `FirstWins` will throw away.
+    discarding: bool,
+    policy: DuplicateKeyPolicy,
+}
+
+impl SpanBuilder {
+    fn enter(&mut self, token: &str) {
+        self.parents.push((self.pointer.len(), self.discarding));
+        self.pointer.push('/');
+        self.pointer.push_str(&escape_token(token));
+    }
+}
+
+impl<'a> Builder<'a> for SpanBuilder {
+    type Value = JSONValue;
+    type Str = Cow<'a, str>;
+    type Object = JSONMap;
+    type Array = JSONArray;
+
+    fn read_string(&mut self, parser: &mut JSON<'a>) -> Result<Option<Self::Str>, ParseError> {
+        self.values.read_string(parser)
+    }
+
+    fn string(&mut self, s: Self::Str) -> JSONValue {
+        self.values.string(s)
+    }
+
+    fn scalar(&mut self, value: JSONValue) -> JSONValue {
+        self.values.scalar(value)
+    }
+
+    fn object(&mut self) -> JSONMap {
+        self.values.object()
+    }
+
+    fn contains_key(&self, object: &JSONMap, key: &str) -> bool {
+        self.values.contains_key(object, key)
+    }
+
+    fn insert(&mut self, object: &mut JSONMap, key: Self::Str, value: JSONValue) {
+        self.values.insert(object, key, value);
+    }
+
+    fn end_object(&mut self, object: JSONMap) -> JSONValue {
+        self.values.end_object(object)
+    }
+
+    fn array(&mut self) -> JSONArray {
+        self.values.array()
+    }
+
+    fn push(&mut self, array: &mut JSONArray, value: JSONValue) {
+        self.values.push(array, value);
+    }
+
+    fn end_array(&mut self, array: JSONArray) -> JSONValue {
+        self.values.end_array(array)
+    }
+
+    // Keep the spans of whichever value ends up in the object.
+    fn begin_member(&mut self, key: &str, duplicate: bool) {
+        self.enter(key);
+        if duplicate && !self.discarding {
+            match self.policy {
+                DuplicateKeyPolicy::LastWins => {
+                    let prefix = format!("{}/", self.pointer);
+                    let pointer = &self.pointer;
+                    self.spans
+                        .retain(|p, _| p != pointer && !p.starts_with(&prefix));
+                }
+                DuplicateKeyPolicy::FirstWins => self.discarding = true,
+                DuplicateKeyPolicy::Error => {}
+            }
+        }
+    }
+
+    fn begin_element(&mut self, index: usize) {
+        self.enter(&index.to_string());
+    }
+
+    fn end_child(&mut self) {
+        let (len, discarding) = self.parents.pop().expect("Child was entered");
+        self.pointer.truncate(len);
+        self.discarding = discarding;
+    }
+
+    fn node(&mut self, value: JSONValue, span: Span) -> JSONValue {
+        if !self.discarding {
+            self.spans.insert(self.pointer.clone(), span);
+        }
+        value
+    }
+}
+
+impl<'a> JSON<'a> {
+    /// Parses `json` and also returns the span of every node, keyed by its
+    /// JSON Pointer with the root at "". Object entries map to the span of the
+    /// value, not the key.
+    pub fn parse_with_spans(
+        json: &'a str,
+        config: &ParserConfig,
+    ) -> Result<(JSONValue, HashMap<String, Span>), ParseError> {
+        let mut parser = JSON::new(json);
+        parser.config = config.clone();
+        let mut builder = SpanBuilder {
+            values: ValueBuilder::new(config),
+            spans: HashMap::new(),
+            pointer: String::new(),
+            parents: vec![],
+            discarding: false,
+            policy: config.duplicate_keys,
+        };
+        let value = parser.parse_document_with(&mut builder)?;
+        Ok((value, builder.spans))
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{JSONResult, ParserConfig, JSON};
+
+/// Parses back-to-back values from one input, such as `{}[]1"x"`, with only
+/// optional whitespace between them. Iteration stops after the first error.
+#[derive(Debug)]
+pub struct StreamParser<'a> {
+    parser: JSON<'a>,
+    done: bool,
+}
+
+impl<'a> StreamParser<'a> {
+    pub fn new(json: &'a str) -> Self {
+        StreamParser {
+            parser: JSON::new(json),
+            done: false,
+        }
+    }
+
+    /// The node limit applies to each value separately; the input length
+    /// limit to the whole input.
+    pub fn with_config(json: &'a str, config: &ParserConfig) -> Self {
+        let mut stream = StreamParser::new(json);
+        stream.parser.config = config.clone();
+        stream
+    }
+
+    /// Parses the next value, leaving the cursor just after it. Returns `None`
+    /// once only whitespace remains.
+    pub fn parse_next(&mut self) -> Option<JSONResult> {
+        if self.done {
+            return None;
+        }
+        let parser = &mut self.parser;
+        let result = parser.check_input_length().and_then(|()| {
+            parser.skip_whitespace()?;
+            if parser.at_end() {
+                return Ok(None);
+            }
+            parser.nodes = 0;
+            let value = parser.parse_value()?;
+            // Errors skipped in recovery mode still end the stream.
+            if !parser.errors.is_empty() {
+                return Err(parser.errors.remove(0));
+            }
+            Ok(Some(value))
+        });
+        match result {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl Iterator for StreamParser<'_> {
+    type Item = JSONResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.parse_next()
+    }
+}
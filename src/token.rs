@@ -0,0 +1,128 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{JSONValue, Number, ParseError, ParserConfig, JSON};
+
+/// A single lexical token. Numbers are split the same way as `JSONValue`:
+/// integer literals that fit in an i64 become `Integer`, everything else
+/// `Number`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Token {
+    BraceOpen,
+    BraceClose,
+    BracketOpen,
+    BracketClose,
+    Colon,
+    Comma,
+    String(String),
+    Number(f64),
+    Integer(i64),
+    /// Only produced when `preserve_number_literals` is set.
+    RawNumber(Number),
+    True,
+    False,
+    Null,
+}
+
+/// Splits input into tokens without checking that they form a valid document.
+/// Iteration stops after the first error.
+#[derive(Debug)]
+pub struct Tokenizer<'a> {
+    parser: JSON<'a>,
+    done: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(json: &'a str) -> Self {
+        Tokenizer {
+            parser: JSON::new(json),
+            done: false,
+        }
+    }
+
+    /// Only the settings for comments, non-finite numbers, number literals,
+    /// keyword case and string length affect tokenizing.
+    pub fn with_config(json: &'a str, config: &ParserConfig) -> Self {
+        let mut tokenizer = Tokenizer::new(json);
+        tokenizer.parser.config = config.clone();
+        tokenizer
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token>, ParseError> {
+        let parser = &mut self.parser;
+        parser.skip_whitespace()?;
+        let punctuation = match parser.peek() {
+            None => return Ok(None),
+            Some(b'{') => Some(Token::BraceOpen),
+            Some(b'}') => Some(Token::BraceClose),
+            Some(b'[') => Some(Token::BracketOpen),
+            Some(b']') => Some(Token::BracketClose),
+            Some(b':') => Some(Token::Colon),
+            Some(b',') => Some(Token::Comma),
+            Some(_) => None,
+        };
+        if let Some(token) = punctuation {
+            parser.increment(1);
+            return Ok(Some(token));
+        }
+        let mut keywords = vec![
+            ("true", JSONValue::True),
+            ("false", JSONValue::False),
+            ("null", JSONValue::Null),
+        ];
+        if parser.config.allow_non_finite {
+            keywords.push(("NaN", JSONValue::Number(f64::NAN)));
+            keywords.push(("Infinity", JSONValue::Number(f64::INFINITY)));
+            keywords.push(("-Infinity", JSONValue::Number(f64::NEG_INFINITY)));
+        }
+        let mut value = None;
+        for (search, keyword) in keywords {
+            value = parser.parse_keyword(search, keyword)?;
+            if value.is_some() {
+                break;
+            }
+        }
+        if value.is_none() {
+            value = parser.parse_string()?;
+        }
+        if value.is_none() {
+            value = parser.parse_number()?;
+        }
+        match value {
+            Some(JSONValue::String(s)) => Ok(Some(Token::String(s))),
+            Some(JSONValue::Number(n)) => Ok(Some(Token::Number(n))),
+            Some(JSONValue::Integer(n)) => Ok(Some(Token::Integer(n))),
+            Some(JSONValue::RawNumber(s)) => Ok(Some(Token::RawNumber(s))),
+            Some(JSONValue::True) => Ok(Some(Token::True)),
+            Some(JSONValue::False) => Ok(Some(Token::False)),
+            Some(JSONValue::Null) => Ok(Some(Token::Null)),
+            _ => {
+                let ch = parser.char_at(parser.i).unwrap_or_default();
+                Err(parser.error(
+                    ParseError::UnexpectedToken,
+                    format!("Unexpected character '{}'", ch),
+                ))
+            }
+        }
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<Token, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_token() {
+            Ok(Some(token)) => Some(Ok(token)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
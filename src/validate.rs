@@ -0,0 +1,51 @@
+use crate::{Builder, JSONValue, ParseError, JSON};
+
+// Checks strings without decoding them and builds nothing.
+struct Validator;
+
+impl<'a> Builder<'a> for Validator {
+    type Value = ();
+    // The raw text of a string, escapes and all.
+    type Str = &'a str;
+    type Object = ();
+    type Array = ();
+
+    fn read_string(&mut self, parser: &mut JSON<'a>) -> Result<Option<&'a str>, ParseError> {
+        if parser.peek() != Some(b'"') {
+            return Ok(None);
+        }
+        parser.increment(1);
+        let start = parser.i;
+        parser.unescape(&mut ())?;
+        Ok(Some(&parser.json[start..parser.i - 1]))
+    }
+
+    fn string(&mut self, _: &'a str) {}
+
+    fn scalar(&mut self, _: JSONValue) {}
+
+    fn object(&mut self) {}
+
+    // Keys aren't kept, so duplicates go unnoticed.
+    fn contains_key(&self, _: &(), _: &str) -> bool {
+        false
+    }
+
+    fn insert(&mut self, _: &mut (), _: &'a str, _: ()) {}
+
+    fn end_object(&mut self, _: ()) {}
+
+    fn array(&mut self) {}
+
+    fn push(&mut self, _: &mut (), _: ()) {}
+
+    fn end_array(&mut self, _: ()) {}
+}
+
+impl JSON<'_> {
+    /// Checks that `json` is a single well-formed value without building it.
+    /// Errors match what `parse` reports for the same input.
+    pub fn validate(json: &str) -> Result<(), ParseError> {
+        JSON::new(json).parse_document_with(&mut Validator)
+    }
+}
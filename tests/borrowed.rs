@@ -0,0 +1,47 @@
+use mson_parser::{JSONValueRef, ParserConfig, JSON};
+use std::borrow::Cow;
+
+#[test]
+fn plain_keys_and_strings_are_borrowed() {
+    let input = r#"{"plain": "text", "esc\n": "a\tb"}"#;
+    let value = JSON::parse_borrowed(input).unwrap();
+    let object = match &value {
+        JSONValueRef::Object(o) => o,
+        other => panic!("Expected an object, got {:?}", other),
+    };
+    for (key, val) in object {
+        let string = match val {
+            JSONValueRef::String(s) => s,
+            other => panic!("Expected a string, got {:?}", other),
+        };
+        if key == "plain" {
+            assert!(matches!(key, Cow::Borrowed(_)));
+            assert!(matches!(string, Cow::Borrowed("text")));
+            assert!(input.as_bytes().as_ptr_range().contains(&key.as_ptr()));
+        } else {
+            assert_eq!(key, "esc\n");
+            assert!(matches!(key, Cow::Owned(_)));
+            assert!(matches!(string, Cow::Owned(_)));
+        }
+    }
+    assert_eq!(value.into_owned(), JSON::parse(input).unwrap());
+}
+
+#[test]
+fn agrees_with_parse() {
+    let config = ParserConfig::new()
+        .recover_errors(true)
+        .allow_trailing_commas(true);
+    for input in [
+        r#"{"a": [1, "x", {"b": null}],}"#,
+        "[1, x, 3]",
+        r#"{"a" 1}"#,
+    ] {
+        assert_eq!(
+            JSON::parse_borrowed_with_config(input, &config).map(JSONValueRef::into_owned),
+            JSON::parse_with_config(input, &config),
+            "{}",
+            input
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use mson_parser::{json, ConvertError, FromJson, JSONValue};
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl FromJson for Point {
+    fn from_json(v: &JSONValue) -> Result<Self, ConvertError> {
+        let field = |name: &str| f64::from_json(v.get(name).unwrap_or(&JSONValue::Null));
+        Ok(Point {
+            x: field("x")?,
+            y: field("y")?,
+        })
+    }
+}
+
+#[test]
+fn converts_into_nested_types() {
+    let grid: Vec<Vec<f64>> = json!([[1, 2.5], [], [-3]]).into_typed().unwrap();
+    assert_eq!(grid, [vec![1.0, 2.5], vec![], vec![-3.0]]);
+
+    let counts: BTreeMap<String, Vec<i64>> = json!({"a": [1], "b": [2, 3]}).into_typed().unwrap();
+    assert_eq!(counts["b"], [2, 3]);
+    assert_eq!(counts.len(), 2);
+
+    let points: Vec<Point> = json!([{"x": 1, "y": 2}]).into_typed().unwrap();
+    assert_eq!(points, [Point { x: 1.0, y: 2.0 }]);
+
+    assert!(matches!(
+        json!([[1, "2"]]).into_typed::<Vec<Vec<f64>>>(),
+        Err(ConvertError::InvalidType(..))
+    ));
+    assert!(matches!(
+        json!({"a": [1.5]}).into_typed::<BTreeMap<String, Vec<i64>>>(),
+        Err(ConvertError::OutOfRange(..))
+    ));
+    assert!(Point::from_json(&json!({"x": 1})).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn round_trips_through_to_json() {
+    use mson_parser::ToJson;
+    use std::collections::HashMap;
+
+    let names = vec!["ann".to_string(), "bob \"b\"".to_string()];
+    let value = names.to_json();
+    assert_eq!(value, json!(["ann", "bob \"b\""]));
+    assert_eq!(Vec::<String>::from_json(&value), Ok(names));
+
+    let mut flags = HashMap::new();
+    flags.insert("on".to_string(), true);
+    flags.insert("off".to_string(), false);
+    let value = flags.to_json();
+    assert_eq!(value, json!({"on": true, "off": false}));
+    assert_eq!(HashMap::<String, bool>::from_json(&value), Ok(flags));
+
+    assert_eq!(Some(1i64).to_json(), json!(1));
+    assert_eq!(None::<i64>.to_json(), json!(null));
+}
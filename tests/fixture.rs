@@ -0,0 +1,101 @@
+use mson_parser::{JSONValue, JSON};
+
+// A deterministic pseudo-random document exercising every kind of token.
+struct Generator(u64);
+
+impl Generator {
+    fn next(&mut self, n: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % n
+    }
+
+    fn string(&mut self) -> String {
+        const PIECES: [&str; 8] = ["a", "key", "é", "😀", "\\n", "\\\"", "\\u0041", " "];
+        let len = self.next(6);
+        let body: String = (0..len)
+            .map(|_| PIECES[self.next(PIECES.len() as u64) as usize])
+            .collect();
+        format!("\"{}\"", body)
+    }
+
+    fn value(&mut self, depth: usize, out: &mut String) {
+        let kind = if depth > 5 {
+            self.next(6)
+        } else {
+            self.next(8)
+        };
+        match kind {
+            0 => out.push_str(&self.string()),
+            1 => out.push_str(&(self.next(1 << 40) as i64 - (1 << 39)).to_string()),
+            2 => out.push_str(&format!(
+                "{}.{}e-{}",
+                self.next(1000),
+                self.next(1000),
+                self.next(20)
+            )),
+            3 => out.push_str("18446744073709551615"),
+            4 => out.push_str(["true", "false", "null"][self.next(3) as usize]),
+            5 => out.push_str("-0.0"),
+            6 => {
+                out.push('[');
+                for i in 0..self.next(6) {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    self.value(depth + 1, out);
+                }
+                out.push(']');
+            }
+            _ => {
+                out.push_str("{\n");
+                for i in 0..self.next(6) {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    out.push_str(&format!("\"k{}\": ", i));
+                    self.value(depth + 1, out);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn from_serde(value: serde_json::Value) -> JSONValue {
+    use serde_json::Value;
+    match value {
+        Value::Null => JSONValue::Null,
+        Value::Bool(b) => JSONValue::from(b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => JSONValue::Integer(i),
+            None => JSONValue::Number(n.as_f64().unwrap()),
+        },
+        Value::String(s) => JSONValue::String(s),
+        Value::Array(a) => a.into_iter().map(from_serde).collect(),
+        Value::Object(o) => o.into_iter().map(|(k, v)| (k, from_serde(v))).collect(),
+    }
+}
+
+#[test]
+fn large_fixture_matches_serde_json() {
+    let mut generator = Generator(42);
+    let mut json = String::from("[");
+    for i in 0..20_000 {
+        if i > 0 {
+            json.push(',');
+        }
+        generator.value(0, &mut json);
+    }
+    json.push(']');
+    assert!(json.len() > 500_000, "{}", json.len());
+
+    let value = JSON::parse(&json).unwrap();
+    assert_eq!(value, from_serde(serde_json::from_str(&json).unwrap()));
+    assert_eq!(JSON::parse_bytes(json.as_bytes()).as_ref(), Ok(&value));
+    assert_eq!(JSON::parse_borrowed(&json).unwrap().into_owned(), value);
+    assert_eq!(JSON::parse(&value.to_string()).as_ref(), Ok(&value));
+    assert_eq!(JSON::validate(&json), Ok(()));
+}
@@ -0,0 +1,44 @@
+use mson_parser::{json, JSONValue};
+
+#[test]
+fn round_trips_mixed_document() {
+    let doc = json!({
+        "": 1,
+        "a": 2,
+        "b": {"c.d": [1, {"": null}], "e\\f": true, "g": {}, "h": []},
+        "s": "x"
+    });
+    let flat = doc.flatten();
+    assert_eq!(flat.get("\\e"), Some(&json!(1)));
+    assert_eq!(flat.get("a"), Some(&json!(2)));
+    assert_eq!(flat.get("b.c\\.d.1.\\e"), Some(&json!(null)));
+    assert_eq!(flat.get("b.e\\\\f"), Some(&json!(true)));
+    assert_eq!(JSONValue::unflatten(&flat), doc);
+}
+
+#[test]
+fn flatten_depth_keeps_deeper_values_whole() {
+    let doc = json!({"a": {"b": {"c": 1}}, "list": [[1, 2]], "s": "x"});
+    let flat = doc.flatten_depth(1);
+    assert_eq!(flat.len(), 3);
+    assert_eq!(flat["a"], json!({"b": {"c": 1}}));
+    assert_eq!(flat["list"], json!([[1, 2]]));
+    assert_eq!(flat["s"], json!("x"));
+    assert_eq!(JSONValue::unflatten(&flat), doc);
+
+    let flat = doc.flatten_depth(2);
+    assert_eq!(flat["a.b"], json!({"c": 1}));
+    assert_eq!(flat["list.0"], json!([1, 2]));
+    assert_eq!(doc.flatten_depth(3), doc.flatten());
+    assert_eq!(doc.flatten_depth(0)[""], doc);
+}
+
+#[test]
+fn empty_key_does_not_collide_with_scalar_root() {
+    let doc = json!({"": 1, "a": 2});
+    assert_eq!(JSONValue::unflatten(&doc.flatten()), doc);
+
+    let scalar = json!(1);
+    assert_eq!(scalar.flatten().get(""), Some(&scalar));
+    assert_eq!(JSONValue::unflatten(&scalar.flatten()), scalar);
+}
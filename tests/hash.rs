@@ -0,0 +1,26 @@
+use mson_parser::{json, HashableValue, JSON};
+use std::collections::HashSet;
+
+#[test]
+fn hash_set_deduplicates_equal_values() {
+    let first = JSON::parse(r#"{"a": 1, "b": [true, null]}"#).unwrap();
+    let second = JSON::parse(r#"{"b": [true, null], "a": 1}"#).unwrap();
+
+    let values = [
+        json!(f64::NAN),
+        json!(f64::NAN),
+        json!(0.0),
+        json!(-0.0),
+        first,
+        second,
+        json!(1),
+        json!(1.0),
+        json!({"a": 2}),
+    ];
+    let set: HashSet<HashableValue> = values.iter().cloned().map(HashableValue).collect();
+    // NaNs collapse, as do both zeros and the two orderings of the object.
+    // `1` and `1.0` stay apart, matching `==`.
+    assert_eq!(set.len(), 6);
+    assert!(set.contains(&HashableValue(json!(f64::NAN))));
+    assert!(set.contains(&HashableValue(json!({"b": [true, null], "a": 1}))));
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "intern_keys")]
+
+use mson_parser::{JSONValue, ParserConfig, JSON};
+
+#[test]
+fn intern_keys_shares_storage_between_records() {
+    let json = format!("[{}]", vec![r#"{"id":1,"name":"x"}"#; 1000].join(","));
+    let key_ptr = |value: &JSONValue, i: usize, key: &str| {
+        let (key, _) = value[i].entries().find(|(k, _)| *k == key).unwrap();
+        key.as_ptr()
+    };
+
+    let config = ParserConfig::new().intern_keys(true);
+    let value = JSON::parse_with_config(&json, &config).unwrap();
+    for i in 1..1000 {
+        assert_eq!(key_ptr(&value, 0, "id"), key_ptr(&value, i, "id"));
+        assert_eq!(key_ptr(&value, 0, "name"), key_ptr(&value, i, "name"));
+    }
+    assert_eq!(value, JSON::parse(&json).unwrap());
+
+    let value = JSON::parse(&json).unwrap();
+    assert_ne!(key_ptr(&value, 0, "id"), key_ptr(&value, 1, "id"));
+}
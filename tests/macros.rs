@@ -0,0 +1,47 @@
+use mson_parser::{json, JSONValue};
+
+#[test]
+fn json_macro_matches_hand_built_values() {
+    let name = "mson";
+    let tags: Vec<JSONValue> = vec!["a".into(), "b".into()];
+    let key = String::from("dynamic");
+    let value = json!({
+        "name": name,
+        "version": 1i64,
+        "ratio": 0.5,
+        "tags": tags,
+        "missing": null,
+        "nested": {"list": [1i64, [true, false], {}], "empty": []},
+        (key): 2i64 + 3
+    });
+
+    let object = |entries: Vec<(&str, JSONValue)>| {
+        JSONValue::Object(entries.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    };
+    let string = |s: &str| JSONValue::String(s.to_string());
+    let nested = object(vec![
+        (
+            "list",
+            JSONValue::Array(vec![
+                JSONValue::Integer(1),
+                JSONValue::Array(vec![JSONValue::True, JSONValue::False]),
+                object(vec![]),
+            ]),
+        ),
+        ("empty", JSONValue::Array(vec![])),
+    ]);
+    let expected = object(vec![
+        ("name", string("mson")),
+        ("version", JSONValue::Integer(1)),
+        ("ratio", JSONValue::Number(0.5)),
+        ("tags", JSONValue::Array(vec![string("a"), string("b")])),
+        ("missing", JSONValue::Null),
+        ("nested", nested),
+        ("dynamic", JSONValue::Integer(5)),
+    ]);
+
+    assert_eq!(value, expected);
+    assert_eq!(json!(null), JSONValue::Null);
+    assert_eq!(json!([]), JSONValue::Array(vec![]));
+    assert_eq!(json!({}), object(vec![]));
+}
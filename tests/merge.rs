@@ -0,0 +1,72 @@
+use mson_parser::{json, ArrayMerge};
+
+#[test]
+fn merge_patch_follows_rfc_7386() {
+    let mut doc = json!({"a": {"b": {"c": 1, "d": 2}, "e": 3}, "list": [1, 2, 3]});
+    doc.merge_patch(&json!({"a": {"b": {"c": null}, "f": 4}, "list": [9]}));
+    assert_eq!(
+        doc,
+        json!({"a": {"b": {"d": 2}, "e": 3, "f": 4}, "list": [9]})
+    );
+
+    let mut doc = json!({"a": {"b": 1}});
+    doc.merge_patch(&json!({"a": null}));
+    assert_eq!(doc, json!({}));
+
+    let mut doc = json!([1, 2]);
+    doc.merge_patch(&json!({"a": {"b": null, "c": 1}}));
+    assert_eq!(doc, json!({"a": {"c": 1}}));
+
+    let mut doc = json!({"a": 1});
+    doc.merge_patch(&json!("text"));
+    assert_eq!(doc, json!("text"));
+}
+
+#[test]
+fn deep_merge_array_strategies() {
+    let base = json!({"a": {"list": [1, 2], "keep": true}, "b": [3]});
+    let other = json!({"a": {"list": [9], "new": null}, "b": "replaced"});
+
+    let mut replaced = base.clone();
+    replaced.deep_merge(other.clone(), ArrayMerge::Replace);
+    assert_eq!(
+        replaced,
+        json!({"a": {"list": [9], "keep": true, "new": null}, "b": "replaced"})
+    );
+
+    let mut concatenated = base;
+    concatenated.deep_merge(other, ArrayMerge::Concat);
+    assert_eq!(
+        concatenated,
+        json!({"a": {"list": [1, 2, 9], "keep": true, "new": null}, "b": "replaced"})
+    );
+}
+
+#[test]
+fn merge_arrays_by_key_replaces_matching_records() {
+    let mut users = json!([
+        {"id": 1, "name": "ann"},
+        {"id": 2, "name": "bob"},
+        {"name": "anonymous"}
+    ]);
+    let updates = json!([
+        {"id": 2, "name": "robert", "admin": true},
+        {"id": 3, "name": "cy"},
+        {"name": "guest"}
+    ]);
+    users.merge_arrays_by_key(&updates, "id");
+    assert_eq!(
+        users,
+        json!([
+            {"id": 1, "name": "ann"},
+            {"id": 2, "name": "robert", "admin": true},
+            {"name": "anonymous"},
+            {"id": 3, "name": "cy"},
+            {"name": "guest"}
+        ])
+    );
+
+    let mut object = json!({"id": 1});
+    object.merge_arrays_by_key(&updates, "id");
+    assert_eq!(object, json!({"id": 1}));
+}
@@ -0,0 +1,182 @@
+use std::convert::TryFrom;
+
+use mson_parser::{json, JSONValue, Number, ParseError, ParserConfig, SerializerConfig, JSON};
+
+#[test]
+fn preserved_literal_survives_round_trip() {
+    let config = ParserConfig::new().preserve_number_literals(true);
+    let value = JSON::parse_with_config(r#"{"a":1.10,"b":[-0,1E+2]}"#, &config).unwrap();
+    assert_eq!(value["a"].as_f64(), Some(1.1));
+    assert_eq!(value.to_string_sorted(), r#"{"a":1.10,"b":[-0,1E+2]}"#);
+}
+
+#[test]
+fn parses_exponents() {
+    for json in ["1e5", "1E5", "1e+5"] {
+        assert_eq!(
+            JSON::parse(json),
+            Ok(JSONValue::Number(100000.0)),
+            "{}",
+            json
+        );
+    }
+    assert_eq!(JSON::parse("1e-5"), Ok(JSONValue::Number(0.00001)));
+}
+
+#[test]
+fn integers_and_floats_stay_distinct() {
+    assert_eq!(
+        JSON::parse("9007199254740993"),
+        Ok(JSONValue::Integer(9007199254740993))
+    );
+    assert_eq!(JSON::parse("1.0"), Ok(JSONValue::Number(1.0)));
+    assert_ne!(JSON::parse("1"), JSON::parse("1.0"));
+    // Past u64::MAX only a float can hold the value.
+    assert_eq!(
+        JSON::parse("18446744073709551616"),
+        Ok(JSONValue::Number(18446744073709551616.0))
+    );
+}
+
+#[test]
+fn converts_between_integers_and_values() {
+    assert_eq!(JSONValue::from(-5i64), JSONValue::Integer(-5));
+    assert_eq!(JSONValue::from(5u64), JSONValue::Integer(5));
+    assert_eq!(
+        JSONValue::from(u64::MAX),
+        JSONValue::Number(u64::MAX as f64)
+    );
+    assert_eq!(i64::try_from(JSONValue::Integer(-5)), Ok(-5));
+    assert_eq!(u64::try_from(JSONValue::Integer(5)), Ok(5));
+    assert!(u64::try_from(JSONValue::Integer(-5)).is_err());
+    assert!(i64::try_from(JSONValue::Number(1.5)).is_err());
+    assert_eq!(i64::try_from(JSONValue::Number(3.0)), Ok(3));
+    assert_eq!(u64::try_from(JSONValue::Number(3.0)), Ok(3));
+    assert!(u64::try_from(JSONValue::Number(-3.0)).is_err());
+}
+
+#[test]
+fn bare_numbers_keep_every_digit() {
+    let mut n: i64 = 0;
+    for digit in 1..=18 {
+        n = n * 10 + digit % 10;
+        assert_eq!(JSON::parse(&n.to_string()), Ok(JSONValue::Integer(n)));
+        assert_eq!(JSON::parse(&(-n).to_string()), Ok(JSONValue::Integer(-n)));
+    }
+    assert_eq!(JSON::parse("123"), Ok(JSONValue::Integer(123)));
+    assert_eq!(JSON::parse("-5"), Ok(JSONValue::Integer(-5)));
+    assert_eq!(JSON::parse("0"), Ok(JSONValue::Integer(0)));
+    assert_eq!(JSON::parse("2.5"), Ok(JSONValue::Number(2.5)));
+}
+
+#[test]
+fn rejects_leading_zeros() {
+    for json in ["01", "-01", "00", "[007]"] {
+        assert!(
+            matches!(JSON::parse(json), Err(ParseError::ExpectedDigit(..))),
+            "{}",
+            json
+        );
+    }
+    assert_eq!(JSON::parse("0"), Ok(JSONValue::Integer(0)));
+    assert_eq!(JSON::parse("-0"), Ok(JSONValue::Integer(0)));
+    assert_eq!(JSON::parse("0.5"), Ok(JSONValue::Number(0.5)));
+    assert_eq!(JSON::parse("0e1"), Ok(JSONValue::Number(0.0)));
+}
+
+#[test]
+fn rejects_incomplete_numbers() {
+    for json in [".5", "5.", "+5", "-", "-.5", "5.e1", "[5.]"] {
+        assert!(
+            matches!(JSON::parse(json), Err(ParseError::ExpectedDigit(..))),
+            "{}",
+            json
+        );
+    }
+    assert_eq!(JSON::parse("0.5"), Ok(JSONValue::Number(0.5)));
+    assert_eq!(JSON::parse("5.0"), Ok(JSONValue::Number(5.0)));
+    assert_eq!(JSON::parse("5"), Ok(JSONValue::Integer(5)));
+    assert_eq!(JSON::parse("-5"), Ok(JSONValue::Integer(-5)));
+}
+
+#[test]
+fn rejects_numbers_that_overflow() {
+    for json in ["1e400", "-1e400", "[1e309]"] {
+        assert!(
+            matches!(JSON::parse(json), Err(ParseError::NumberOutOfRange(..))),
+            "{}",
+            json
+        );
+    }
+    assert_eq!(JSON::parse("1e308"), Ok(JSONValue::Number(1e308)));
+    // Underflow rounds to zero rather than failing.
+    assert_eq!(JSON::parse("1e-400"), Ok(JSONValue::Number(0.0)));
+}
+
+#[test]
+fn non_finite_literals_behind_a_flag() {
+    let config = ParserConfig::new().allow_non_finite(true);
+    let value = JSON::parse_with_config("[NaN, Infinity, -Infinity, 1]", &config).unwrap();
+    assert!(value[0].as_f64().unwrap().is_nan());
+    assert_eq!(value[1], JSONValue::Number(f64::INFINITY));
+    assert_eq!(value[2], JSONValue::Number(f64::NEG_INFINITY));
+    for json in ["NaN", "Infinity", "-Infinity"] {
+        assert!(JSON::parse(json).is_err(), "{}", json);
+    }
+
+    assert_eq!(value.to_string(), "[null,null,null,1]");
+    let literals = SerializerConfig::new().allow_non_finite(true);
+    assert_eq!(
+        value.to_string_with_config(&literals),
+        "[NaN,Infinity,-Infinity,1]"
+    );
+    assert_eq!(json!(1.5).to_string_with_config(&literals), "1.5");
+}
+
+#[test]
+fn integral_extraction() {
+    let parse = |json: &str| JSON::parse(json).unwrap();
+    assert_eq!(parse("42").as_i64(), Some(42));
+    assert_eq!(parse("42.0").as_i64(), Some(42));
+    assert_eq!(parse("42.0").as_u64(), Some(42));
+    assert_eq!(parse("4.2e1").as_u64(), Some(42));
+    assert_eq!(parse("42.5").as_i64(), None);
+    assert_eq!(parse("42.5").as_u64(), None);
+    assert_eq!(parse("-1").as_u64(), None);
+    assert_eq!(parse("-1").as_i64(), Some(-1));
+    // Out of i64 range, so it parses as a float that rounds up past u64::MAX.
+    assert_eq!(parse("18446744073709551615").as_u64(), None);
+    let config = ParserConfig::new().preserve_number_literals(true);
+    let literal = JSON::parse_with_config("18446744073709551615", &config).unwrap();
+    assert_eq!(literal.as_u64(), Some(u64::MAX));
+    assert_eq!(literal.as_i64(), None);
+    assert_eq!(parse("1e19").as_i64(), None);
+    assert_eq!(parse("1e20").as_u64(), None);
+    assert_eq!(parse("9223372036854775807").as_i64(), Some(i64::MAX));
+    assert_eq!(parse("-9223372036854775808").as_i64(), Some(i64::MIN));
+    assert_eq!(parse("\"42\"").as_i64(), None);
+}
+
+#[test]
+fn number_literals_are_checked() {
+    for literal in ["0", "-1", "1.10", "1e5", "-0.5E-3"] {
+        let number: Number = literal.parse().unwrap();
+        assert_eq!(number.as_str(), literal);
+        assert_eq!(JSONValue::from(number).to_string(), literal);
+    }
+    assert!(matches!(
+        "1.0x".parse::<Number>(),
+        Err(ParseError::ExpectedEndOfInput(..))
+    ));
+    assert!(matches!(
+        "not a number".parse::<Number>(),
+        Err(ParseError::ExpectedDigit(..))
+    ));
+    for invalid in ["", " 1", "1 ", "01", "1.", "+1", "\u{FEFF}1"] {
+        assert!(invalid.parse::<Number>().is_err(), "{:?}", invalid);
+    }
+    assert!(matches!(
+        "1e400".parse::<Number>(),
+        Err(ParseError::NumberOutOfRange(..))
+    ));
+}
@@ -0,0 +1,411 @@
+use mson_parser::{json, DuplicateKeyPolicy, ParseError, ParserConfig, StreamParser, JSON};
+
+#[test]
+fn parse_error_displays_kind_and_message() {
+    let error = JSON::parse("[1,").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unexpected end of input at line 1, column 4: Expected a value"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_error_works_as_boxed_error() {
+    fn parse(json: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        Ok(JSON::parse(json)?.to_string().len())
+    }
+    assert_eq!(parse("[1]").unwrap(), 3);
+    let error = parse(r#"{"a" 1}"#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Expected token at line 1, column 6: Expected :."
+    );
+    assert!(error.downcast_ref::<ParseError>().is_some());
+}
+
+#[test]
+fn errors_report_line_and_column() {
+    let error = JSON::parse("[1,\n  2,\n  x]").unwrap_err();
+    assert_eq!((error.line(), error.column()), (3, 3));
+
+    // Columns count characters, not bytes.
+    let error = JSON::parse(r#"["é€", x]"#).unwrap_err();
+    assert_eq!((error.line(), error.column()), (1, 8));
+    let error = JSON::parse("{\"😀\":\n 1 x}").unwrap_err();
+    assert_eq!((error.line(), error.column()), (2, 4));
+}
+
+#[test]
+fn unexpected_character_is_not_end_of_input() {
+    let error = JSON::parse("[1,]").unwrap_err();
+    assert!(
+        matches!(error, ParseError::UnexpectedToken(..)),
+        "{:?}",
+        error
+    );
+    assert_eq!((error.line(), error.column()), (1, 4));
+    assert_eq!(
+        error.to_string(),
+        "Unexpected token at line 1, column 4: Unexpected character ']'"
+    );
+}
+
+#[test]
+fn empty_and_blank_input_is_an_error() {
+    for json in ["", "   ", "\n\t"] {
+        assert!(
+            matches!(JSON::parse(json), Err(ParseError::UnexpectedEndOfInput(..))),
+            "{:?}",
+            json
+        );
+    }
+}
+
+#[test]
+fn rejects_trailing_values() {
+    for json in ["1 2", "true false", "[] []"] {
+        assert!(
+            matches!(JSON::parse(json), Err(ParseError::ExpectedEndOfInput(..))),
+            "{:?}",
+            json
+        );
+    }
+}
+
+#[test]
+fn parse_bytes_checks_utf8_and_skips_bom() {
+    assert_eq!(
+        JSON::parse_bytes(b"\xEF\xBB\xBF{\"a\":1}"),
+        Ok(json!({"a": 1}))
+    );
+    assert_eq!(JSON::parse_bytes("[\"é\"]".as_bytes()), Ok(json!(["é"])));
+    let error = JSON::parse_bytes(b"[\"ab\xFF\"]").unwrap_err();
+    assert!(matches!(error, ParseError::InvalidUtf8(..)));
+    assert_eq!((error.line(), error.column()), (1, 5));
+}
+
+#[test]
+fn depth_limit_allows_exactly_max_depth() {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(JSON::parse_with_depth(&nested(10), 10).is_ok());
+    assert!(matches!(
+        JSON::parse_with_depth(&nested(11), 10),
+        Err(ParseError::DepthLimitExceeded(..))
+    ));
+    assert!(matches!(
+        JSON::parse_with_depth(r#"{"a":{"b":{"c":1}}}"#, 2),
+        Err(ParseError::DepthLimitExceeded(..))
+    ));
+    // The default limit turns stack-exhausting input into an error.
+    assert!(matches!(
+        JSON::parse(&nested(100_000)),
+        Err(ParseError::DepthLimitExceeded(..))
+    ));
+}
+
+#[test]
+fn parse_with_config_applies_options() {
+    let config = ParserConfig::new().max_depth(2);
+    assert_eq!(JSON::parse_with_config("[[1]]", &config), Ok(json!([[1]])));
+    assert!(JSON::parse_with_config("[[[1]]]", &config).is_err());
+    assert!(JSON::parse_with_config("[[[1]]]", &ParserConfig::new()).is_ok());
+    assert_eq!(ParserConfig::new(), ParserConfig::default());
+}
+
+#[test]
+fn config_options_combine() {
+    let json = "[1, // one\n 2,]";
+    let config = ParserConfig::new()
+        .allow_comments(true)
+        .allow_trailing_commas(true);
+    assert_eq!(JSON::parse_with_config(json, &config), Ok(json!([1, 2])));
+    let comments_only = ParserConfig::new().allow_comments(true);
+    assert!(JSON::parse_with_config(json, &comments_only).is_err());
+    let commas_only = ParserConfig::new().allow_trailing_commas(true);
+    assert!(JSON::parse_with_config(json, &commas_only).is_err());
+    assert_eq!(ParserConfig::new(), ParserConfig::default());
+}
+
+#[test]
+fn comments_are_whitespace_when_allowed() {
+    let config = ParserConfig::new().allow_comments(true);
+    let parse = |json: &str| JSON::parse_with_config(json, &config);
+    let members = r#"{"a":1 /* c */, /* d */ "b":2 // e
+    }"#;
+    assert_eq!(parse(members), Ok(json!({"a": 1, "b": 2})));
+    assert_eq!(parse("[1, // x\n 2 /* y */]"), Ok(json!([1, 2])));
+    assert!(JSON::parse(members).is_err());
+
+    let error = parse("[1 /* x").unwrap_err();
+    assert!(matches!(error, ParseError::UnexpectedEndOfInput(..)));
+    assert_eq!(error.message(), "Unterminated block comment");
+    assert!(parse("/ 1").is_err());
+    assert!(JSON::parse("/* x */ 1").is_err());
+}
+
+#[test]
+fn trailing_commas_only_when_allowed() {
+    assert!(JSON::parse(r#"{"a":1,}"#).is_err());
+    assert!(JSON::parse("[1,2,]").is_err());
+    let config = ParserConfig::new().allow_trailing_commas(true);
+    assert_eq!(
+        JSON::parse_with_config(r#"{"a":1,}"#, &config),
+        Ok(json!({"a": 1}))
+    );
+    assert_eq!(
+        JSON::parse_with_config("[1,2,]", &config),
+        Ok(json!([1, 2]))
+    );
+    // Only one comma, and only after an element.
+    assert!(JSON::parse_with_config("[1,,]", &config).is_err());
+    assert!(JSON::parse_with_config("[,]", &config).is_err());
+}
+
+#[test]
+fn duplicate_key_policies() {
+    let json = r#"{"a":1,"a":2}"#;
+    let parse = |policy| JSON::parse_with_config(json, &ParserConfig::new().duplicate_keys(policy));
+    assert_eq!(JSON::parse(json), Ok(json!({"a": 2})));
+    assert_eq!(parse(DuplicateKeyPolicy::LastWins), Ok(json!({"a": 2})));
+    assert_eq!(parse(DuplicateKeyPolicy::FirstWins), Ok(json!({"a": 1})));
+    let error = parse(DuplicateKeyPolicy::Error).unwrap_err();
+    assert!(matches!(error, ParseError::DuplicateKey(..)));
+    assert_eq!(error.message(), "a");
+    assert_eq!(error.column(), 8);
+}
+
+#[test]
+fn trailing_whitespace_is_allowed() {
+    assert_eq!(JSON::parse("1 "), Ok(json!(1)));
+    assert_eq!(JSON::parse("{}\n"), Ok(json!({})));
+    assert_eq!(JSON::parse(" \t[true]\r\n "), Ok(json!([true])));
+}
+
+#[test]
+fn unterminated_object_is_an_error() {
+    let error = JSON::parse(r#"{"a":1"#).unwrap_err();
+    assert!(matches!(error, ParseError::UnexpectedEndOfInput(..)));
+    for json in ["{", r#"{"a""#, r#"{"a":"#] {
+        assert!(
+            matches!(JSON::parse(json), Err(ParseError::UnexpectedEndOfInput(..))),
+            "{}",
+            json
+        );
+    }
+}
+
+#[test]
+fn parse_many_reports_malformed_lines() {
+    let input = "{\"a\":1}\n{\"a\":\n\n[2]\n";
+    let results: Vec<_> = JSON::parse_many(input).collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0], Ok(json!({"a": 1})));
+    let error = results[1].as_ref().unwrap_err();
+    assert!(matches!(error, ParseError::UnexpectedEndOfInput(..)));
+    assert_eq!(error.line(), 2);
+    assert_eq!(results[2], Ok(json!([2])));
+}
+
+#[test]
+fn recovery_reports_every_error() {
+    let config = ParserConfig::new().recover_errors(true);
+    let errors =
+        JSON::parse_with_errors(r#"{"a": tru, "b": 1, "c": [1, @], "d": 2}"#, &config).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].column(), 7);
+    assert_eq!(errors[1].column(), 29);
+
+    let errors = JSON::parse_with_errors(r#"[tru, @]"#, &ParserConfig::new()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        JSON::parse_with_errors("[1, 2]", &config),
+        Ok(json!([1, 2]))
+    );
+}
+
+#[test]
+fn missing_object_key_is_an_error() {
+    for (json, column) in [
+        (r#"{"a":1,"#, 8),
+        ("{1:2}", 2),
+        (r#"{"a":1,}"#, 8),
+        (r#"{"a":1, x}"#, 9),
+    ] {
+        let error = JSON::parse(json).unwrap_err();
+        assert!(
+            matches!(error, ParseError::ExpectedObjectKey(..)),
+            "{}",
+            json
+        );
+        assert_eq!(error.column(), column, "{}", json);
+    }
+}
+
+#[test]
+fn leading_bom_is_skipped() {
+    assert_eq!(JSON::parse("\u{FEFF}{}"), Ok(json!({})));
+    assert_eq!(JSON::parse("\u{FEFF} [1] "), Ok(json!([1])));
+    // Only one, and only at the start.
+    assert!(JSON::parse("\u{FEFF}\u{FEFF}{}").is_err());
+    assert!(JSON::parse("[\u{FEFF}1]").is_err());
+}
+
+#[test]
+fn size_limits_reject_large_input() {
+    let config = ParserConfig::new().max_nodes(4);
+    assert!(JSON::parse_with_config("[1, 2]", &config).is_ok());
+    assert!(matches!(
+        JSON::parse_with_config("[1, [2, 3], 4]", &config),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+
+    let config = ParserConfig::new().max_input_length(8);
+    assert!(JSON::parse_with_config("[1, 2]", &config).is_ok());
+    assert!(matches!(
+        JSON::parse_with_config("[1, 2, 3, 4]", &config),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+}
+
+#[test]
+fn stream_parser_yields_concatenated_values() {
+    let values: Vec<_> = StreamParser::new(r#"{}[]1"x""#).collect();
+    assert_eq!(
+        values,
+        [Ok(json!({})), Ok(json!([])), Ok(json!(1)), Ok(json!("x"))]
+    );
+
+    let mut stream = StreamParser::new(" [1]\n\n{\"a\" 2} 3");
+    assert_eq!(stream.next(), Some(Ok(json!([1]))));
+    assert!(matches!(
+        stream.next(),
+        Some(Err(ParseError::ExpectedToken(..)))
+    ));
+    assert_eq!(stream.next(), None);
+    assert_eq!(StreamParser::new("  ").next(), None);
+}
+
+#[test]
+fn stream_parser_reports_recovered_errors() {
+    let config = ParserConfig::new().recover_errors(true);
+    let mut stream = StreamParser::with_config("[1, @] 2", &config);
+    let error = stream.next().unwrap().unwrap_err();
+    assert_eq!(error.column(), 5);
+    assert_eq!(stream.next(), None);
+}
+
+#[test]
+fn max_string_length_rejects_long_strings() {
+    let config = ParserConfig::new().max_string_length(5);
+    let parse = |json: &str| JSON::parse_with_config(json, &config);
+    assert_eq!(parse(r#""abcde""#), Ok(json!("abcde")));
+    assert!(matches!(
+        parse(r#""abcdef""#),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+    assert!(matches!(
+        parse(r#"{"abcdef":1}"#),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+}
+
+#[test]
+fn unterminated_containers_and_strings_report_the_end() {
+    let cases = [
+        (r#"{"a":1"#, "Unexpected end of input. Expected '}'"),
+        ("[", "Unexpected end of input. Expected ']'"),
+        ("[1", "Unexpected end of input. Expected ']'"),
+        (r#"{"a":[1"#, "Unexpected end of input. Expected ']'"),
+        (r#""abc"#, "Unexpected end of input. Expected '\"'"),
+        (r#"{"a":"b"#, "Unexpected end of input. Expected '\"'"),
+    ];
+    for (json, message) in cases {
+        let error = JSON::parse(json).unwrap_err();
+        assert!(
+            matches!(error, ParseError::UnexpectedEndOfInput(..)),
+            "{}",
+            json
+        );
+        assert_eq!(error.message(), message, "{}", json);
+        assert_eq!(error.column(), json.len() + 1, "{}", json);
+    }
+}
+
+#[test]
+fn keyword_case_only_matters_by_default() {
+    assert!(JSON::parse("TRUE").is_err());
+    assert!(JSON::parse("[Null]").is_err());
+
+    let config = ParserConfig::new().case_insensitive_keywords(true);
+    let parse = |json: &str| JSON::parse_with_config(json, &config);
+    assert_eq!(parse("TRUE"), Ok(json!(true)));
+    assert_eq!(
+        parse(r#"{"a": False, "b": nULL}"#),
+        Ok(json!({"a": false, "b": null}))
+    );
+    assert!(parse("TRUEX").is_err());
+    assert!(parse("NaN").is_err());
+
+    let config = config.allow_non_finite(true);
+    let value = JSON::parse_with_config("[NAN, -infinity]", &config).unwrap();
+    assert!(value[0].as_f64().unwrap().is_nan());
+    assert_eq!(value[1].as_f64(), Some(f64::NEG_INFINITY));
+}
+
+#[test]
+fn max_nodes_rejects_array_just_over_limit() {
+    let config = ParserConfig::new().max_nodes(8);
+    // The array itself counts as a node.
+    assert_eq!(
+        JSON::parse_with_config("[1,2,3,4,5,6,7]", &config),
+        Ok(json!([1, 2, 3, 4, 5, 6, 7]))
+    );
+    assert!(matches!(
+        JSON::parse_with_config("[1,2,3,4,5,6,7,8]", &config),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+}
+
+#[test]
+fn limits_are_fatal_in_recovery_mode() {
+    let config = ParserConfig::new().max_nodes(2).recover_errors(true);
+    let errors = JSON::parse_with_errors("[1,2,3,4,5,6,7,8]", &config).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], ParseError::SizeLimitExceeded(..)));
+
+    let config = ParserConfig::new().max_depth(1).recover_errors(true);
+    let errors = JSON::parse_with_errors("[[1],[2],[3]]", &config).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], ParseError::DepthLimitExceeded(..)));
+}
+
+#[test]
+fn max_string_length_counts_decoded_bytes() {
+    let config = ParserConfig::new().max_string_length(5);
+    let parse = |json: &str| JSON::parse_with_config(json, &config);
+    assert_eq!(parse(r#""abcde""#), Ok(json!("abcde")));
+    assert!(matches!(
+        parse(r#""abcdef""#),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+    assert_eq!(parse(r#""abcd\n""#), Ok(json!("abcd\n")));
+    assert!(matches!(
+        parse(r#""abcd\ne""#),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+    assert_eq!(parse(r#""abcé""#), Ok(json!("abcé")));
+    assert!(matches!(
+        parse(r#""abcdé""#),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+    assert_eq!(parse(r#""abc\u00e9""#), Ok(json!("abcé")));
+    assert!(matches!(
+        parse(r#""abcd\u00e9""#),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+    assert!(matches!(
+        parse(r#"{"abcdef":1}"#),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+}
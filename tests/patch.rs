@@ -0,0 +1,160 @@
+use mson_parser::{diff, json, PatchError};
+
+#[test]
+fn rfc_6902_examples() {
+    let ok = [
+        (
+            json!({"foo": "bar"}),
+            json!([{"op": "add", "path": "/baz", "value": "qux"}]),
+            json!({"baz": "qux", "foo": "bar"}),
+        ),
+        (
+            json!({"foo": ["bar", "baz"]}),
+            json!([{"op": "add", "path": "/foo/1", "value": "qux"}]),
+            json!({"foo": ["bar", "qux", "baz"]}),
+        ),
+        (
+            json!({"baz": "qux", "foo": "bar"}),
+            json!([{"op": "remove", "path": "/baz"}]),
+            json!({"foo": "bar"}),
+        ),
+        (
+            json!({"foo": ["bar", "qux", "baz"]}),
+            json!([{"op": "remove", "path": "/foo/1"}]),
+            json!({"foo": ["bar", "baz"]}),
+        ),
+        (
+            json!({"baz": "qux", "foo": "bar"}),
+            json!([{"op": "replace", "path": "/baz", "value": "boo"}]),
+            json!({"baz": "boo", "foo": "bar"}),
+        ),
+        (
+            json!({"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}}),
+            json!([{"op": "move", "from": "/foo/waldo", "path": "/qux/thud"}]),
+            json!({"foo": {"bar": "baz"}, "qux": {"corge": "grault", "thud": "fred"}}),
+        ),
+        (
+            json!({"foo": ["all", "grass", "cows", "eat"]}),
+            json!([{"op": "move", "from": "/foo/1", "path": "/foo/3"}]),
+            json!({"foo": ["all", "cows", "eat", "grass"]}),
+        ),
+        (
+            json!({"baz": "qux", "foo": ["a", 2, "c"]}),
+            json!([
+                {"op": "test", "path": "/baz", "value": "qux"},
+                {"op": "test", "path": "/foo/1", "value": 2}
+            ]),
+            json!({"baz": "qux", "foo": ["a", 2, "c"]}),
+        ),
+        (
+            json!({"foo": "bar"}),
+            json!([{"op": "add", "path": "/child", "value": {"grandchild": {}}}]),
+            json!({"foo": "bar", "child": {"grandchild": {}}}),
+        ),
+        (
+            json!({"foo": "bar"}),
+            json!([{"op": "add", "path": "/baz", "value": "qux", "xyz": 123}]),
+            json!({"foo": "bar", "baz": "qux"}),
+        ),
+        (
+            json!({"/": 9, "~1": 10}),
+            json!([{"op": "test", "path": "/~01", "value": 10}]),
+            json!({"/": 9, "~1": 10}),
+        ),
+        (
+            json!({"foo": ["bar"]}),
+            json!([{"op": "add", "path": "/foo/-", "value": ["abc", "def"]}]),
+            json!({"foo": ["bar", ["abc", "def"]]}),
+        ),
+    ];
+    for (mut doc, patch, expected) in ok {
+        doc.apply_patch(&patch).unwrap();
+        assert_eq!(doc, expected, "{}", patch);
+    }
+
+    let mut doc = json!({"baz": "qux"});
+    let patch = json!([{"op": "test", "path": "/baz", "value": "bar"}]);
+    assert!(matches!(
+        doc.apply_patch(&patch),
+        Err(PatchError::TestFailed(_))
+    ));
+
+    let mut doc = json!({"foo": "bar"});
+    let patch = json!([{"op": "add", "path": "/baz/bat", "value": "qux"}]);
+    assert!(matches!(
+        doc.apply_patch(&patch),
+        Err(PatchError::PathNotFound(_))
+    ));
+
+    let mut doc = json!({"/": 9, "~1": 10});
+    let patch = json!([{"op": "test", "path": "/~01", "value": "10"}]);
+    assert!(matches!(
+        doc.apply_patch(&patch),
+        Err(PatchError::TestFailed(_))
+    ));
+}
+
+#[test]
+fn diff_produces_a_patch_from_a_to_b() {
+    let pairs = [
+        (
+            json!({"a": 1, "b": {"c": [1, 2, 3], "d": "x"}, "gone": true}),
+            json!({"a": 2, "b": {"c": [1, 5], "d": "x", "e": null}, "new": [1]}),
+        ),
+        (json!([1, {"a/b": 1}]), json!([1, {"a/b": 2, "~": 3}, 4])),
+        (json!({"a": 1}), json!([1])),
+        (json!("x"), json!("x")),
+    ];
+    for (a, b) in pairs {
+        let patch = diff(&a, &b);
+        let mut patched = a.clone();
+        patched.apply_patch(&patch).unwrap();
+        assert_eq!(patched, b, "{}", patch);
+    }
+    assert_eq!(diff(&json!({"a": [1]}), &json!({"a": [1]})), json!([]));
+}
+
+#[test]
+fn add_appends_through_dash() {
+    let mut doc = json!({"list": [1, 2]});
+    doc.apply_patch(&json!([{"op": "add", "path": "/list/-", "value": 9}]))
+        .unwrap();
+    assert_eq!(doc, json!({"list": [1, 2, 9]}));
+}
+
+#[test]
+fn replace_and_test_reject_dash() {
+    let original = json!({"list": [1, 2]});
+    for op in ["replace", "test"] {
+        let mut doc = original.clone();
+        let patch = json!([{"op": op, "path": "/list/-", "value": 9}]);
+        assert!(matches!(
+            doc.apply_patch(&patch),
+            Err(PatchError::PathNotFound(_))
+        ));
+        assert_eq!(doc, original);
+    }
+}
+
+#[test]
+fn test_compares_numbers_by_value() {
+    let mut doc = json!({"a": 1, "b": [1, {"c": 2.0}]});
+    let patch = json!([
+        {"op": "test", "path": "/a", "value": 1.0},
+        {"op": "test", "path": "/b", "value": [1.0, {"c": 2}]}
+    ]);
+    assert_eq!(doc.apply_patch(&patch), Ok(()));
+
+    for value in [
+        json!(1.5),
+        json!("1"),
+        json!([1.0]),
+        json!({"c": 2, "d": 3}),
+    ] {
+        let patch = json!([{"op": "test", "path": "/b/1", "value": value}]);
+        assert!(matches!(
+            doc.apply_patch(&patch),
+            Err(PatchError::TestFailed(_))
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use mson_parser::{json, PathError};
+
+#[test]
+fn pointer_lookup() {
+    let doc = json!({"a/b": 1, "m~n": 2, "list": [10, 20], "obj": {"x": "y"}});
+    assert_eq!(doc.pointer(""), Some(&doc));
+    assert_eq!(doc.pointer("/a~1b"), Some(&json!(1)));
+    assert_eq!(doc.pointer("/m~0n"), Some(&json!(2)));
+    assert_eq!(doc.pointer("/list/1"), Some(&json!(20)));
+    assert_eq!(doc.pointer("/obj/x"), Some(&json!("y")));
+
+    assert_eq!(doc.pointer("/list/2"), None);
+    assert_eq!(doc.pointer("/list/01"), None);
+    assert_eq!(doc.pointer("/list/-1"), None);
+    assert_eq!(doc.pointer("/obj/x/y"), None);
+    assert_eq!(doc.pointer("/obj/0"), None);
+    assert_eq!(doc.pointer("/m~2n"), None);
+    assert_eq!(doc.pointer("list"), None);
+}
+
+#[test]
+fn visit_collects_leaf_paths() {
+    let doc = json!({"a": [1, {"b/c": true}], "d": {}, "e": "x"});
+    let mut leaves = vec![];
+    doc.visit(|path, value| {
+        if !value.is_object() && !value.is_array() {
+            leaves.push(path.to_string());
+        }
+    });
+    leaves.sort();
+    assert_eq!(leaves, ["/a/0", "/a/1/b~1c", "/e"]);
+
+    let mut paths = vec![];
+    doc.visit(|path, _| paths.push(path.to_string()));
+    assert_eq!(paths.len(), 7);
+    assert_eq!(paths[0], "");
+}
+
+#[test]
+fn visit_mut_redacts_values() {
+    let mut doc = json!({"user": {"name": "x", "password": "p"}, "tokens": [{"password": "q"}]});
+    doc.visit_mut(|path, value| {
+        if path.ends_with("/password") {
+            *value = json!("***");
+        }
+    });
+    assert_eq!(
+        doc,
+        json!({"user": {"name": "x", "password": "***"}, "tokens": [{"password": "***"}]})
+    );
+}
+
+#[test]
+fn take_and_replace_through_pointer_mut() {
+    let mut value = json!({"a": {"b": [1, {"c": "old"}]}});
+    let slot = value.pointer_mut("/a/b/1/c").unwrap();
+    assert_eq!(slot.replace(json!("new")), json!("old"));
+    assert_eq!(value, json!({"a": {"b": [1, {"c": "new"}]}}));
+
+    let taken = value.pointer_mut("/a/b").unwrap().take();
+    assert_eq!(taken, json!([1, {"c": "new"}]));
+    assert_eq!(value, json!({"a": {"b": null}}));
+}
+
+#[test]
+fn set_path_creates_parents_and_delete_path_removes() {
+    let mut doc = json!({});
+    doc.set_path("/a/b/0/c", json!(true)).unwrap();
+    assert_eq!(doc, json!({"a": {"b": [{"c": true}]}}));
+    doc.set_path("/a/d", json!(1)).unwrap();
+    assert!(matches!(
+        doc.set_path("/a/d/e", json!(2)),
+        Err(PathError::NotAContainer(..))
+    ));
+
+    assert_eq!(doc.delete_path("/a/b/0/c"), Some(json!(true)));
+    assert_eq!(doc, json!({"a": {"b": [{}], "d": 1}}));
+    assert_eq!(doc.delete_path("/a/b/0/c"), None);
+    assert_eq!(doc.delete_path(""), None);
+}
+
+#[test]
+fn pointer_mut_appends_through_dash() {
+    let mut doc = json!({"a": [1], "b": {"-": 2}});
+    *doc.pointer_mut("/a/-").unwrap() = json!(3);
+    assert_eq!(doc["a"], json!([1, 3]));
+    assert_eq!(doc.pointer_mut("/b/-"), Some(&mut json!(2)));
+    assert!(doc.pointer_mut("/a/-/x").is_none());
+    assert_eq!(doc["a"], json!([1, 3]));
+}
+
+#[test]
+fn set_path_appends_through_dash() {
+    let mut doc = json!({"list": [1]});
+    doc.set_path("/list/-", json!(2)).unwrap();
+    assert_eq!(doc, json!({"list": [1, 2]}));
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "preserve_order")]
+
+use mson_parser::{json, prettify, JSONValue, Key, JSON};
+
+#[test]
+fn round_trip_keeps_key_order() {
+    let json = r#"{"z":1,"a":{"y":true,"b":null,"x":[{"q":1,"c":2}]},"m":"s"}"#;
+    let value = JSON::parse(json).unwrap();
+    let keys: Vec<&str> = value.keys().map(Key::as_str).collect();
+    assert_eq!(keys, ["z", "a", "m"]);
+    assert_eq!(value.to_string(), json);
+}
+
+#[test]
+fn prettify_is_idempotent() {
+    let json = r#"{"z":[1,{"y":null,"b":2}],"a":{}}"#;
+    let once = prettify(json, 4).unwrap();
+    assert_eq!(prettify(&once, 4).unwrap(), once);
+    assert_eq!(JSON::parse(&once).unwrap().to_string(), json);
+}
+
+#[test]
+fn remove_keeps_order_of_remaining_keys() {
+    let mut value = JSON::parse(r#"{"c":1,"b":2,"a":3}"#).unwrap();
+    value.remove("c");
+    value.insert("d", 4);
+    assert_eq!(value.to_string(), r#"{"b":2,"a":3,"d":4}"#);
+}
+
+#[test]
+fn sort_keys_orders_nested_objects() {
+    let mut value: JSONValue = json!({"b": {"d": 1, "c": 2}, "a": [{"f": 1, "e": 2}]});
+    value.sort_keys();
+    assert_eq!(
+        value.to_string(),
+        r#"{"a":[{"e":2,"f":1}],"b":{"c":2,"d":1}}"#
+    );
+}
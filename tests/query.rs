@@ -0,0 +1,40 @@
+use mson_parser::{json, JSONValue};
+
+fn store() -> JSONValue {
+    json!({"store": {
+        "book": [
+            {"title": "Sayings", "price": 8.95},
+            {"title": "Sword", "price": 12.99},
+            {"title": "Moby Dick", "price": 8.99, "isbn": "0-553-21311-3"}
+        ],
+        "bicycle": {"color": "red", "price": 19.95}
+    }})
+}
+
+fn sorted(values: Vec<&JSONValue>) -> Vec<f64> {
+    let mut numbers: Vec<f64> = values.into_iter().filter_map(JSONValue::as_f64).collect();
+    numbers.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    numbers
+}
+
+#[test]
+fn queries_with_wildcards_and_indices() {
+    let doc = store();
+    assert_eq!(doc.query("$.store.book[0].title"), [&json!("Sayings")]);
+    assert_eq!(
+        doc.query("$['store']['book'][-1].isbn"),
+        [&json!("0-553-21311-3")]
+    );
+    assert_eq!(doc.query("$.store.book[*].title").len(), 3);
+    assert_eq!(doc.query("$.store.*").len(), 2);
+    assert_eq!(doc.query("$.store.book[5]"), Vec::<&JSONValue>::new());
+    assert_eq!(doc.query("$"), [&doc]);
+}
+
+#[test]
+fn recursive_descent() {
+    let doc = store();
+    assert_eq!(sorted(doc.query("$..book[*].price")), [8.95, 8.99, 12.99]);
+    assert_eq!(sorted(doc.query("$..price")), [8.95, 8.99, 12.99, 19.95]);
+    assert_eq!(doc.query("$..isbn"), [&json!("0-553-21311-3")]);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+use mson_parser::{json, ParseError, ParserConfig, JSON};
+use std::io::{self, Cursor, Read};
+
+// Yields its data, then fails.
+struct FailingReader(Cursor<&'static [u8]>);
+
+impl Read for FailingReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.0.read(buf)? {
+            0 => Err(io::Error::other("connection reset")),
+            n => Ok(n),
+        }
+    }
+}
+
+#[test]
+fn reads_from_cursor() {
+    let cursor = Cursor::new(r#"{"a": [1, "x"]}"#);
+    assert_eq!(JSON::from_reader(cursor), Ok(json!({"a": [1, "x"]})));
+}
+
+#[test]
+fn reports_io_errors() {
+    let error = JSON::from_reader(FailingReader(Cursor::new(b"[1,\n2"))).unwrap_err();
+    assert!(matches!(error, ParseError::Io(..)));
+    assert_eq!(error.message(), "connection reset");
+    assert_eq!((error.line(), error.column()), (2, 2));
+}
+
+fn large_document() -> String {
+    let records: Vec<String> = (0..50_000)
+        .map(|i| format!(r#"{{"id":{},"name":"record {}","tags":["a","b"]}}"#, i, i))
+        .collect();
+    format!("[{}]", records.join(","))
+}
+
+#[test]
+fn reads_multi_megabyte_cursor() {
+    let json = large_document();
+    assert!(json.len() > 2 * 1024 * 1024);
+    let expected = JSON::parse(&json).unwrap();
+    assert_eq!(
+        JSON::from_reader(Cursor::new(json.as_bytes())),
+        Ok(expected.clone())
+    );
+    assert_eq!(
+        JSON::from_reader_buffered(Cursor::new(json.as_bytes())),
+        Ok(expected.clone())
+    );
+    let config = ParserConfig::new().max_input_length(json.len());
+    assert_eq!(
+        JSON::from_reader_with_config(Cursor::new(json.as_bytes()), &config),
+        Ok(expected)
+    );
+}
+
+#[test]
+fn stops_reading_past_max_input_length() {
+    // Whitespace forever: without the limit this would never finish.
+    let config = ParserConfig::new().max_input_length(1024 * 1024);
+    assert!(matches!(
+        JSON::from_reader_with_config(io::repeat(b' '), &config),
+        Err(ParseError::SizeLimitExceeded(..))
+    ));
+
+    let mut source = Cursor::new(vec![b' '; 4096]);
+    let config = ParserConfig::new().max_input_length(100);
+    assert!(JSON::from_reader_with_config(&mut source, &config).is_err());
+    assert_eq!(source.position(), 101);
+}
+
+#[test]
+fn reader_applies_config() {
+    let config = ParserConfig::new().allow_trailing_commas(true);
+    assert!(JSON::from_reader(Cursor::new("[1,]")).is_err());
+    assert!(JSON::from_reader_with_config(Cursor::new("[1,]"), &config).is_ok());
+}
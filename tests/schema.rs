@@ -0,0 +1,44 @@
+use mson_parser::{json, SchemaError};
+
+fn error(pointer: &str, message: &str) -> SchemaError {
+    SchemaError {
+        pointer: pointer.to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn reports_missing_fields_and_type_mismatches() {
+    let schema = json!({
+        "type": "object",
+        "required": ["id", "name"],
+        "properties": {
+            "id": {"type": "integer"},
+            "tags": {"type": "array", "items": {"type": "string"}}
+        }
+    });
+    assert_eq!(
+        json!({"id": 1, "name": "x", "tags": []}).validate_schema(&schema),
+        Ok(())
+    );
+
+    let mut errors = json!({"id": "1", "tags": ["a", 2]})
+        .validate_schema(&schema)
+        .unwrap_err();
+    errors.sort_by(|a, b| a.pointer.cmp(&b.pointer));
+    assert_eq!(
+        errors,
+        [
+            error("", "Missing required property 'name'"),
+            error("/id", "Expected integer, found string"),
+            error("/tags/1", "Expected string, found number"),
+        ]
+    );
+    assert_eq!(
+        errors[1].to_string(),
+        "Schema violation at '/id': Expected integer, found string"
+    );
+
+    let errors = json!([1]).validate_schema(&schema).unwrap_err();
+    assert_eq!(errors, [error("", "Expected object, found array")]);
+}
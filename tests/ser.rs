@@ -0,0 +1,169 @@
+use mson_parser::{json, minify, prettify, stringify, Indent, JSONValue, SerializerConfig, JSON};
+
+#[test]
+fn precision_limits_significant_digits() {
+    let third = json!(1.0 / 3.0);
+    assert_eq!(third.to_string(), "0.3333333333333333");
+    let config = SerializerConfig::new().precision(2);
+    assert_eq!(third.to_string_with_config(&config), "0.33");
+    assert_eq!(json!(2.0 / 3.0).to_string_with_config(&config), "0.67");
+}
+
+fn parse(json: &str) -> JSONValue {
+    JSON::parse(json).unwrap()
+}
+
+fn round_trip(value: &JSONValue) -> JSONValue {
+    parse(&stringify(value))
+}
+
+#[test]
+fn stringify_round_trips() {
+    let nested = parse(r#"{"a": {"b": {"c": [1, 2.5, {"d": null}]}}, "e": [true, false]}"#);
+    assert_eq!(round_trip(&nested), nested);
+
+    assert_eq!(stringify(&parse("[]")), "[]");
+    assert_eq!(stringify(&parse("{}")), "{}");
+    let empties = parse(r#"{"a": [], "b": {}, "c": [[], {}]}"#);
+    assert_eq!(round_trip(&empties), empties);
+
+    let text = JSONValue::String("say \"hi\"\nback\\slash".to_string());
+    assert_eq!(stringify(&text), r#""say \"hi\"\nback\\slash""#);
+    assert_eq!(round_trip(&text), text);
+}
+
+#[test]
+fn display_matches_stringify() {
+    let value = parse(r#"{"a": [1, 2.5, null], "b": "x"}"#);
+    assert_eq!(format!("{}", value), stringify(&value));
+    assert_eq!(
+        JSONValue::String("a \"quoted\"\tvalue".to_string()).to_string(),
+        r#""a \"quoted\"\tvalue""#
+    );
+    assert_eq!(JSONValue::Number(1.0).to_string(), "1.0");
+    assert_eq!(JSONValue::Number(f64::NAN).to_string(), "null");
+}
+
+#[test]
+fn sorted_output_ignores_insertion_order() {
+    let first = parse(r#"{"b": {"y": 1, "x": 2}, "a": 1, "é": 3, "Z": 4}"#);
+    let second = parse(r#"{"Z": 4, "é": 3, "a": 1, "b": {"x": 2, "y": 1}}"#);
+    assert_eq!(first.to_string_sorted(), second.to_string_sorted());
+    assert_eq!(
+        first.to_string_sorted(),
+        r#"{"Z":4,"a":1,"b":{"x":2,"y":1},"é":3}"#
+    );
+}
+
+#[test]
+fn pretty_output() {
+    let value = json!({"a": [1, {"b": null}, {}, []]});
+    assert_eq!(
+        value.to_string_pretty(2),
+        r#"{
+  "a": [
+    1,
+    {
+      "b": null
+    },
+    {},
+    []
+  ]
+}"#
+    );
+    assert_eq!(
+        value.to_string_pretty_with(Indent::Tab),
+        "{\n\t\"a\": [\n\t\t1,\n\t\t{\n\t\t\t\"b\": null\n\t\t},\n\t\t{},\n\t\t[]\n\t]\n}"
+    );
+    assert_eq!(json!([]).to_string_pretty(4), "[]");
+    assert_eq!(json!({}).to_string_pretty_with(Indent::Tab), "{}");
+}
+
+#[test]
+fn ascii_only_escapes_non_ascii() {
+    let config = SerializerConfig::new().ascii_only(true);
+    let value = json!({"é": "é😀\tx"});
+    let ascii = value.to_string_with_config(&config);
+    assert_eq!(ascii, r#"{"\u00e9":"\u00e9\ud83d\ude00\tx"}"#);
+    assert_eq!(JSON::parse(&ascii), Ok(value.clone()));
+    assert_eq!(value.to_string(), "{\"é\":\"é😀\\tx\"}");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn writers_match_to_string() {
+    let value = json!({"a": [1, 2.5, "x"], "b": {"c": null}});
+    let mut compact = Vec::new();
+    value.to_writer(&mut compact).unwrap();
+    assert_eq!(String::from_utf8(compact).unwrap(), value.to_string());
+    let mut pretty = Vec::new();
+    value.to_writer_pretty(&mut pretty, 2).unwrap();
+    assert_eq!(
+        String::from_utf8(pretty).unwrap(),
+        value.to_string_pretty(2)
+    );
+}
+
+#[test]
+fn canonical_form_follows_rfc_8785() {
+    let canonical = |json: &str| JSON::parse(json).unwrap().to_canonical_string();
+    assert_eq!(canonical("1e21"), "1e+21");
+    assert_eq!(canonical("1e20"), "100000000000000000000");
+    assert_eq!(canonical("0.0000001"), "1e-7");
+    assert_eq!(canonical("0.000001"), "0.000001");
+    assert_eq!(canonical("-0"), "0");
+    assert_eq!(canonical("-0.0"), "0");
+    assert_eq!(
+        canonical(r#"{ "b" : [1.50, true], "a" : "é" }"#),
+        r#"{"a":"é","b":[1.5,true]}"#
+    );
+    // Keys sort by UTF-16 code units, which puts a surrogate pair before
+    // U+FB33 even though its code point is larger.
+    let value = json!({"\u{fb33}": 1, "\u{1f600}": 2, "\u{20ac}": 3, "z": 4});
+    assert_eq!(
+        value.to_canonical_string(),
+        "{\"z\":4,\"\u{20ac}\":3,\"\u{1f600}\":2,\"\u{fb33}\":1}"
+    );
+}
+
+#[test]
+fn minify_ignores_formatting() {
+    let compact = r#"{"a":[1,2.50,{"b":null}],"c":"x y\n"}"#;
+    let pretty =
+        "{\n  \"a\": [\n    1,\n    2.50,\n    { \"b\" : null }\n  ],\r\n\t\"c\": \"x y\\n\"\n}\n";
+    assert_eq!(minify(compact).unwrap(), compact);
+    assert_eq!(minify(pretty).unwrap(), compact);
+    assert!(minify("[1,").is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn array_writer_streams_elements() {
+    use mson_parser::ArrayWriter;
+
+    let mut writer = ArrayWriter::new(Vec::new());
+    for i in 0..3 {
+        writer.write_element(&json!({"id": [i, "x"]})).unwrap();
+    }
+    let out = String::from_utf8(writer.finish().unwrap()).unwrap();
+    assert_eq!(out, r#"[{"id":[0,"x"]},{"id":[1,"x"]},{"id":[2,"x"]}]"#);
+    let empty = ArrayWriter::new(Vec::new()).finish().unwrap();
+    assert_eq!(empty, b"[]");
+
+    let config = SerializerConfig::new()
+        .indent(Indent::Spaces(2))
+        .sort_keys(true);
+    let mut writer = ArrayWriter::with_config(Vec::new(), &config);
+    writer.write_element(&json!({"b": 1, "a": 2})).unwrap();
+    assert_eq!(writer.finish().unwrap(), br#"[{"a":2,"b":1}]"#);
+}
+
+#[test]
+fn prettify_is_idempotent() {
+    // Single-key objects, since hash maps don't keep key order between parses.
+    let json = r#"{"a":[1,{"b":null},[],{}]}"#;
+    let once = prettify(json, 2).unwrap();
+    assert_eq!(prettify(&once, 2).unwrap(), once);
+    assert_eq!(once, JSON::parse(json).unwrap().to_string_pretty(2));
+    assert!(prettify("{", 2).is_err());
+}
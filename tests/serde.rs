@@ -0,0 +1,40 @@
+#![cfg(feature = "serde")]
+
+use mson_parser::{json, JSONValue, ParserConfig, JSON};
+
+#[test]
+fn round_trips_through_serde_json() {
+    let value = json!({
+        "name": "widget \"quoted\"\n",
+        "count": 3,
+        "price": 9.5,
+        "big": 18446744073709551615u64,
+        "negative": -7,
+        "tags": ["a", [], {}],
+        "nested": {"ok": true, "off": false, "none": null}
+    });
+    let text = serde_json::to_string(&value).unwrap();
+    let back: JSONValue = serde_json::from_str(&text).unwrap();
+    assert_eq!(back, value);
+    assert_eq!(JSON::parse(&text), Ok(value));
+}
+
+#[test]
+fn parses_serde_json_output() {
+    let text = r#"{"a":[1,2.5,"x"],"b":{"c":null}}"#;
+    let value: JSONValue = serde_json::from_str(text).unwrap();
+    assert_eq!(value, json!({"a": [1, 2.5, "x"], "b": {"c": null}}));
+    assert_eq!(serde_json::to_string(&value).unwrap().len(), text.len());
+}
+
+#[test]
+fn raw_numbers_keep_integer_precision() {
+    let config = ParserConfig::new().preserve_number_literals(true);
+    let value =
+        JSON::parse_with_config("[-9223372036854775808,18446744073709551615,1.10]", &config)
+            .unwrap();
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        "[-9223372036854775808,18446744073709551615,1.1]"
+    );
+}
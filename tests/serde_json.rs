@@ -0,0 +1,21 @@
+#![cfg(feature = "serde_json")]
+
+use mson_parser::{json, JSONValue};
+use serde_json::Value;
+
+#[test]
+fn converts_to_and_from_serde_json_values() {
+    let value = json!({"s": "x", "i": -7, "f": 2.5, "b": [true, false, null], "o": {}});
+    let converted = Value::from(value.clone());
+    assert_eq!(
+        converted,
+        serde_json::json!({"s": "x", "i": -7, "f": 2.5, "b": [true, false, null], "o": {}})
+    );
+    assert_eq!(JSONValue::from(converted), value);
+
+    assert_eq!(Value::from(json!(f64::NAN)), Value::Null);
+    assert_eq!(
+        JSONValue::from(serde_json::json!(u64::MAX)),
+        json!(u64::MAX as f64)
+    );
+}
@@ -0,0 +1,45 @@
+use mson_parser::{json, DuplicateKeyPolicy, ParserConfig, Span, JSON};
+
+#[test]
+fn records_span_of_nested_string() {
+    let input = r#"{"a": [1, {"b": "hi"}]}"#;
+    let (value, spans) = JSON::parse_with_spans(input, &ParserConfig::new()).unwrap();
+    assert_eq!(value, json!({"a": [1, {"b": "hi"}]}));
+    let span = spans["/a/1/b"];
+    assert_eq!(span, Span { start: 16, end: 20 });
+    assert_eq!(&input[span.start..span.end], r#""hi""#);
+    assert_eq!(
+        spans[""],
+        Span {
+            start: 0,
+            end: input.len()
+        }
+    );
+}
+
+#[test]
+fn keeps_spans_of_the_value_that_wins() {
+    let input = r#"{"a": {"x": 1}, "a": 2}"#;
+    let config = ParserConfig::new().duplicate_keys(DuplicateKeyPolicy::LastWins);
+    let (_, spans) = JSON::parse_with_spans(input, &config).unwrap();
+    assert_eq!(spans["/a"], Span { start: 21, end: 22 });
+    assert!(!spans.contains_key("/a/x"));
+
+    let config = ParserConfig::new().duplicate_keys(DuplicateKeyPolicy::FirstWins);
+    let (_, spans) = JSON::parse_with_spans(input, &config).unwrap();
+    assert_eq!(spans["/a"], Span { start: 6, end: 14 });
+    assert_eq!(spans["/a/x"], Span { start: 12, end: 13 });
+}
+
+#[test]
+fn agrees_with_parse_on_errors() {
+    let config = ParserConfig::new().recover_errors(true).max_nodes(4);
+    for input in ["[1, x, 3]", r#"{"a": 1 "b": 2}"#, "[1, 2, 3, 4]", "[1"] {
+        assert_eq!(
+            JSON::parse_with_spans(input, &config).map(|(value, _)| value),
+            JSON::parse_with_config(input, &config),
+            "{}",
+            input
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use mson_parser::{escape_string, unescape_string, JSONValue, ParseError, JSON};
+
+fn string(s: &str) -> JSONValue {
+    JSONValue::String(s.to_string())
+}
+
+#[test]
+fn decodes_surrogate_pairs() {
+    assert_eq!(JSON::parse(r#""\ud83d\ude00""#), Ok(string("😀")));
+    assert_eq!(JSON::parse(r#""\uD834\uDD1E clef""#), Ok(string("𝄞 clef")));
+    assert_eq!(JSON::parse(r#""😀""#), Ok(string("😀")));
+    for json in [r#""\ud800""#, r#""\ud800x""#, r#""\ud800A""#, r#""\udc00""#] {
+        assert!(
+            matches!(
+                JSON::parse(json),
+                Err(ParseError::ExpectedUnicodeEscape(..))
+            ),
+            "{}",
+            json
+        );
+    }
+}
+
+#[test]
+fn unterminated_strings_are_errors() {
+    for json in [r#""abc"#, r#"""#, r#"["abc]"#, r#""abc\"#, r#"{"a"#] {
+        assert!(
+            matches!(JSON::parse(json), Err(ParseError::UnexpectedEndOfInput(..))),
+            "{}",
+            json
+        );
+    }
+    let error = JSON::parse(r#""abc"#).unwrap_err();
+    assert_eq!(error.column(), 5);
+}
+
+#[test]
+fn control_characters_must_be_escaped() {
+    for json in ["\"a\nb\"", "\"a\tb\"", "\"\u{0}\"", "[\"\u{1f}\"]"] {
+        assert!(
+            matches!(
+                JSON::parse(json),
+                Err(ParseError::ControlCharacterInString(..))
+            ),
+            "{:?}",
+            json
+        );
+    }
+    assert_eq!(JSON::parse(r#""a\nb""#), Ok(string("a\nb")));
+    assert_eq!(JSON::parse(r#""a\tb""#), Ok(string("a\tb")));
+    assert_eq!(JSON::parse("\"\u{7f}\""), Ok(string("\u{7f}")));
+}
+
+#[test]
+fn decodes_slash_and_backslash_escapes() {
+    assert_eq!(JSON::parse(r#""a\/b""#), Ok(string("a/b")));
+    assert_eq!(JSON::parse(r#""c\\d""#), Ok(string("c\\d")));
+    assert_eq!(JSON::parse(r#""\\\/\"""#), Ok(string("\\/\"")));
+    assert!(matches!(
+        JSON::parse(r#""\x""#),
+        Err(ParseError::ExpectedEscapeChar(..))
+    ));
+}
+
+#[test]
+fn escape_and_unescape_strings() {
+    let raw = "a\"b\\c\n\t\u{1}\u{1f}é😀/";
+    let escaped = escape_string(raw);
+    assert_eq!(escaped, r#"a\"b\\c\n\t\u0001\u001fé😀/"#);
+    assert_eq!(unescape_string(&escaped), Ok(raw.to_string()));
+    assert_eq!(
+        unescape_string(r#"\/\ud83d\ude00\u00e9"#),
+        Ok("/😀é".to_string())
+    );
+
+    assert!(matches!(
+        unescape_string(r#"a"b"#),
+        Err(ParseError::ExpectedEndOfInput(..))
+    ));
+    assert!(matches!(
+        unescape_string(r#"\uD83D"#),
+        Err(ParseError::ExpectedUnicodeEscape(..))
+    ));
+}
@@ -0,0 +1,37 @@
+use mson_parser::{Token, Tokenizer};
+
+#[test]
+fn tokenizes_a_small_document() {
+    let tokens: Result<Vec<Token>, _> =
+        Tokenizer::new(r#"{"a": [1, -2.5, true], "b": null}"#).collect();
+    assert_eq!(
+        tokens.unwrap(),
+        [
+            Token::BraceOpen,
+            Token::String("a".to_string()),
+            Token::Colon,
+            Token::BracketOpen,
+            Token::Integer(1),
+            Token::Comma,
+            Token::Number(-2.5),
+            Token::Comma,
+            Token::True,
+            Token::BracketClose,
+            Token::Comma,
+            Token::String("b".to_string()),
+            Token::Colon,
+            Token::Null,
+            Token::BraceClose,
+        ]
+    );
+}
+
+#[test]
+fn stops_after_the_first_error() {
+    let mut tokens = Tokenizer::new("[1, @, 2]");
+    assert_eq!(tokens.next(), Some(Ok(Token::BracketOpen)));
+    assert_eq!(tokens.next(), Some(Ok(Token::Integer(1))));
+    assert_eq!(tokens.next(), Some(Ok(Token::Comma)));
+    assert!(matches!(tokens.next(), Some(Err(_))));
+    assert_eq!(tokens.next(), None);
+}
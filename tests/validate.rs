@@ -0,0 +1,38 @@
+use mson_parser::JSON;
+
+#[test]
+fn agrees_with_parse() {
+    let deep = format!("{}{}", "[".repeat(200), "]".repeat(200));
+    let inputs = [
+        "null",
+        "  {\"a\": [1, 2.5e3, -0, \"x\\n\\u00e9\\ud83d\\ude00\"], \"b\": {}}  ",
+        "\u{FEFF}[]",
+        "",
+        "   ",
+        "{",
+        "[1,]",
+        "{\"a\":1,}",
+        "{\"a\" 1}",
+        "{1: 2}",
+        "[1 2]",
+        "01",
+        "1.",
+        "-",
+        "1e400",
+        "\"abc",
+        "\"a\\x\"",
+        "\"\\ud800\"",
+        "\"tab\there\"",
+        "tru",
+        "[] []",
+        &deep,
+    ];
+    for input in inputs.iter() {
+        assert_eq!(
+            JSON::validate(input),
+            JSON::parse(input).map(|_| ()),
+            "{:?}",
+            input
+        );
+    }
+}
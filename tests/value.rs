@@ -0,0 +1,458 @@
+use std::convert::TryFrom;
+
+use mson_parser::{json, JSONValue, ParserConfig, JSON};
+
+fn parse(json: &str) -> JSONValue {
+    JSON::parse(json).unwrap()
+}
+
+#[test]
+fn get_and_index() {
+    let doc = parse(r#"{"a":[1,2,{"b":true}]}"#);
+    assert_eq!(
+        doc.get("a").and_then(|a| a.get_index(1)),
+        Some(&JSONValue::Integer(2))
+    );
+    assert_eq!(doc["a"][2]["b"], JSONValue::True);
+    assert_eq!(doc.get("missing"), None);
+    assert_eq!(doc["missing"], JSONValue::Null);
+    assert_eq!(doc["a"][9], JSONValue::Null);
+    assert_eq!(doc.get_index(0), None);
+}
+
+#[test]
+#[should_panic(expected = "Cannot index into a non-object value")]
+fn indexing_an_array_by_key_panics() {
+    let doc = parse(r#"{"a": [1, 2]}"#);
+    let _ = &doc["a"]["b"];
+}
+
+#[test]
+#[should_panic(expected = "Cannot index into a non-array value")]
+fn indexing_an_object_by_position_panics() {
+    let doc = parse(r#"{"a": {"b": 1}}"#);
+    let _ = &doc["a"][0];
+}
+
+#[test]
+fn typed_accessors() {
+    let mut doc = parse(r#"{"s": "x", "n": 1.5, "i": 2, "b": true, "a": [1], "o": {"k": null}}"#);
+    assert_eq!(doc["s"].as_str(), Some("x"));
+    assert_eq!(doc["n"].as_f64(), Some(1.5));
+    assert_eq!(doc["i"].as_f64(), Some(2.0));
+    assert_eq!(doc["b"].as_bool(), Some(true));
+    assert_eq!(doc["a"].as_array(), Some(&vec![JSONValue::Integer(1)]));
+    assert_eq!(doc["o"].as_object().map(|o| o.len()), Some(1));
+    assert_eq!(doc["s"].as_f64(), None);
+    assert_eq!(doc["n"].as_str(), None);
+    assert_eq!(doc["a"].as_object(), None);
+    assert_eq!(doc["o"].as_array(), None);
+    assert_eq!(doc["i"].as_bool(), None);
+
+    let object = doc.as_object_mut().unwrap();
+    object.insert("new".into(), JSONValue::Integer(1));
+    object
+        .get_mut("a")
+        .and_then(JSONValue::as_array_mut)
+        .unwrap()
+        .push(JSONValue::Integer(2));
+    assert_eq!(doc["new"], JSONValue::Integer(1));
+    assert_eq!(doc["a"], parse("[1, 2]"));
+    assert!(doc["s"].clone().as_array_mut().is_none());
+    assert!(parse("[]").as_object_mut().is_none());
+}
+
+#[test]
+fn predicates_and_type_names() {
+    let cases = [
+        (parse("{}"), "object"),
+        (parse("[]"), "array"),
+        (JSONValue::String("s".to_string()), "string"),
+        (JSONValue::Number(1.5), "number"),
+        (JSONValue::Integer(1), "number"),
+        (JSONValue::True, "boolean"),
+        (JSONValue::False, "boolean"),
+        (JSONValue::Null, "null"),
+    ];
+    for (value, name) in &cases {
+        assert_eq!(value.type_name(), *name);
+        let flags = [
+            value.is_object(),
+            value.is_array(),
+            value.is_string(),
+            value.is_number(),
+            value.is_boolean(),
+            value.is_null(),
+        ];
+        let expected =
+            ["object", "array", "string", "number", "boolean", "null"].map(|n| n == *name);
+        assert_eq!(flags, expected, "{:?}", value);
+    }
+}
+
+#[test]
+fn from_integers_and_options() {
+    assert_eq!(JSONValue::from(-1i32), JSONValue::Integer(-1));
+    assert_eq!(
+        JSONValue::from(u32::MAX),
+        JSONValue::Integer(u32::MAX as i64)
+    );
+    assert_eq!(JSONValue::from(i64::MIN), JSONValue::Integer(i64::MIN));
+    assert_eq!(JSONValue::from(7usize), JSONValue::Integer(7));
+    assert_eq!(JSONValue::from(Some(3)), JSONValue::Integer(3));
+    assert_eq!(JSONValue::from(Some("x")), json!("x"));
+    assert_eq!(JSONValue::from(None::<i32>), JSONValue::Null);
+}
+
+#[test]
+fn approx_eq_tolerates_rounding() {
+    let sum = json!({"total": 0.1 + 0.2, "items": [1, 2.0000000001]});
+    let expected = json!({"total": 0.3, "items": [1.0, 2]});
+    assert_ne!(sum, expected);
+    assert!(sum.approx_eq(&expected, 1e-9));
+    assert!(!sum.approx_eq(&expected, 1e-12));
+    assert!(!json!([0.3]).approx_eq(&json!([0.3, 1]), 1.0));
+    assert!(!json!({"a": 1}).approx_eq(&json!({"b": 1}), 1.0));
+    assert!(!json!("0.3").approx_eq(&json!(0.3), 1.0));
+}
+
+// Keys are plain strings unless the `intern_keys` feature is on.
+#[cfg(not(feature = "intern_keys"))]
+#[test]
+fn object_keys_are_strings() {
+    let doc = json!({"a": 1});
+    let keys: Vec<&String> = doc.keys().collect();
+    assert_eq!(keys, ["a"]);
+    let entries: Vec<(&String, &JSONValue)> = doc.as_object().unwrap().iter().collect();
+    assert_eq!(entries, [(&"a".to_string(), &json!(1))]);
+    assert_eq!(doc.entries().collect::<Vec<_>>(), entries);
+}
+
+#[test]
+fn iterates_entries_and_elements() {
+    let doc = json!({"list": [1, "two", null], "obj": {"a": 1, "b": 2}, "s": "x"});
+    let elements: Vec<&JSONValue> = doc["list"].elements().collect();
+    assert_eq!(elements, [&json!(1), &json!("two"), &json!(null)]);
+    assert_eq!(doc["list"].len(), Some(3));
+
+    let mut entries: Vec<(String, i64)> = doc["obj"]
+        .entries()
+        .map(|(k, v)| (k.to_string(), v.as_i64().unwrap()))
+        .collect();
+    entries.sort();
+    assert_eq!(entries, [("a".to_string(), 1), ("b".to_string(), 2)]);
+    assert_eq!(doc["obj"].len(), Some(2));
+
+    // Each iterator is empty for the wrong type.
+    assert_eq!(doc["obj"].elements().count(), 0);
+    assert_eq!(doc["list"].entries().count(), 0);
+    assert_eq!(doc["s"].entries().count() + doc["s"].elements().count(), 0);
+    assert!(json!({}).is_empty() && json!([]).is_empty());
+    assert!(!doc["s"].is_empty());
+}
+
+#[test]
+fn collects_into_arrays_and_objects() {
+    let array: JSONValue = (1..=3).map(JSONValue::from).collect();
+    assert_eq!(array, json!([1, 2, 3]));
+    let object: JSONValue = ["a", "b"]
+        .iter()
+        .enumerate()
+        .map(|(i, k)| (k.to_string(), JSONValue::from(i)))
+        .collect();
+    assert_eq!(object, json!({"a": 0, "b": 1}));
+    let doubled: JSONValue = array
+        .into_iter()
+        .map(|v| JSONValue::from(v.as_f64().unwrap() * 2.0))
+        .collect();
+    assert_eq!(doubled, json!([2.0, 4.0, 6.0]));
+
+    let list = json!([1, "two", null]);
+    assert_eq!((&list).into_iter().count(), 3);
+    assert_eq!((&json!("s")).into_iter().count(), 0);
+}
+
+#[test]
+fn builds_documents_in_place() {
+    let mut doc = json!({});
+    assert_eq!(doc.insert("name", "x"), None);
+    assert_eq!(doc.insert("name", "y"), Some(json!("x")));
+    let mut list = json!([]);
+    list.push(1);
+    list.push("two");
+    doc.insert("list", list);
+    doc.insert("gone", true);
+    assert!(doc.contains_key("gone"));
+    assert_eq!(doc.remove("gone"), Some(json!(true)));
+    assert!(!doc.contains_key("gone"));
+    assert_eq!(doc.remove("gone"), None);
+    assert_eq!(doc, json!({"name": "y", "list": [1, "two"]}));
+
+    // Each method ignores values of the wrong type.
+    let mut scalar = json!(1);
+    assert_eq!(scalar.insert("a", 1), None);
+    scalar.push(2);
+    assert_eq!(scalar.remove("a"), None);
+    assert_eq!(scalar, json!(1));
+}
+
+#[test]
+fn from_vecs_and_maps() {
+    let names = vec!["a".to_string(), "b".to_string()];
+    assert_eq!(JSONValue::from(names), json!(["a", "b"]));
+    let sorted: std::collections::BTreeMap<String, Vec<i32>> =
+        vec![("n".to_string(), vec![1, 2])].into_iter().collect();
+    assert_eq!(JSONValue::from(sorted), json!({"n": [1, 2]}));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn from_hash_map() {
+    let flags: std::collections::HashMap<String, bool> =
+        vec![("on".to_string(), true), ("off".to_string(), false)]
+            .into_iter()
+            .collect();
+    assert_eq!(JSONValue::from(flags), json!({"on": true, "off": false}));
+}
+
+#[test]
+fn parses_with_from_str() {
+    fn port(config: &str) -> Result<f64, mson_parser::ParseError> {
+        let value: JSONValue = config.parse()?;
+        Ok(value["port"].as_f64().unwrap_or(80.0))
+    }
+    assert_eq!(port(r#"{"port": 8080}"#), Ok(8080.0));
+    assert_eq!(port("{}"), Ok(80.0));
+    assert!(port("{").is_err());
+    assert_eq!("[1]".parse::<JSONValue>(), Ok(json!([1])));
+}
+
+#[test]
+fn default_is_null() {
+    assert_eq!(JSONValue::default(), JSONValue::Null);
+    assert_eq!(JSONValue::default(), JSONValue::null());
+}
+
+#[test]
+fn constructors() {
+    assert_eq!(JSONValue::object(), json!({}));
+    assert_eq!(JSONValue::array(), json!([]));
+    assert_eq!(JSONValue::null(), json!(null));
+    let array = JSONValue::array_with_capacity(16);
+    assert_eq!(array, json!([]));
+    assert!(array.as_array().unwrap().capacity() >= 16);
+    assert_eq!(JSONValue::object_with_capacity(16), json!({}));
+}
+
+#[test]
+fn retain_removes_nulls() {
+    let mut value = json!({"a": 1, "b": null, "c": [null, 2, null], "d": null});
+    value.retain(|_, v| !v.is_null());
+    assert_eq!(value, json!({"a": 1, "c": [null, 2, null]}));
+
+    let list = value.pointer_mut("/c").unwrap();
+    list.retain_elements(|v| !v.is_null());
+    assert_eq!(value, json!({"a": 1, "c": [2]}));
+
+    // Other variants are left alone.
+    let mut list = json!([null]);
+    list.retain(|_, _| false);
+    assert_eq!(list, json!([null]));
+}
+
+#[test]
+fn map_leaves_keeps_structure() {
+    let value = json!({"name": "ann", "tags": ["a", {"b": "c"}], "age": 3, "x": null});
+    let upper = value.map_leaves(|leaf| match leaf.as_str() {
+        Some(s) => JSONValue::from(s.to_uppercase()),
+        None => leaf,
+    });
+    assert_eq!(
+        upper,
+        json!({"name": "ANN", "tags": ["A", {"b": "C"}], "age": 3, "x": null})
+    );
+}
+
+#[test]
+fn len_counts_members_and_characters() {
+    assert_eq!(json!([1, [2, 3]]).len(), Some(2));
+    assert_eq!(json!({"a": 1, "b": {"c": 2}}).len(), Some(2));
+    assert_eq!(json!("héllo😀").len(), Some(6));
+    assert_eq!(json!(42).len(), None);
+    assert!(json!("").is_empty());
+    assert!(json!([]).is_empty());
+    assert!(!json!(0).is_empty());
+    assert!(!json!(null).is_empty());
+}
+
+#[test]
+fn get_or_insert_with_builds_nested_objects() {
+    let mut value = json!({"a": {"keep": 1}});
+    *value
+        .get_or_insert_with("a", JSONValue::object)
+        .get_or_insert_with("b", JSONValue::object)
+        .get_or_insert_with("c", || json!(0)) = json!(2);
+    assert_eq!(value, json!({"a": {"keep": 1, "b": {"c": 2}}}));
+
+    // Existing values are returned untouched.
+    let c = value["a"]["b"].clone();
+    assert_eq!(
+        value
+            .get_or_insert_with("a", || unreachable!())
+            .get_or_insert_with("b", || unreachable!()),
+        &c
+    );
+
+    let mut scalar = json!(1);
+    scalar.get_or_insert_with("x", || json!(true));
+    assert_eq!(scalar, json!({"x": true}));
+}
+
+#[test]
+fn orders_by_type_then_contents() {
+    let ordered = [
+        json!(null),
+        json!(false),
+        json!(true),
+        json!(-1.5),
+        json!(1),
+        json!(1.0),
+        json!(2),
+        json!(""),
+        json!("a"),
+        json!([]),
+        json!([1, 2]),
+        json!([2]),
+        json!({}),
+        json!({"b": 1, "a": 2}),
+        json!({"b": 0}),
+    ];
+    for pair in ordered.windows(2) {
+        assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+    }
+    let nan = JSONValue::Number(f64::NAN);
+    assert_eq!(nan.partial_cmp(&json!(1)), None);
+    assert!(nan < json!("a"));
+}
+
+#[test]
+fn sorts_keys_and_arrays_in_place() {
+    let mut value = json!({"b": [{"d": 1, "c": 2}], "a": {"f": null, "e": [3, 1, 2]}});
+    let original = value.clone();
+    value.sort_keys();
+    assert_eq!(value, original);
+    let keys: Vec<&str> = value["b"][0]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(|k| k.as_str())
+        .collect();
+    if cfg!(any(feature = "preserve_order", not(feature = "std"))) {
+        assert_eq!(keys, ["c", "d"]);
+    }
+
+    let mut list = json!([{"n": 3}, {"n": 1}, {"n": 2}, [2, 1]]);
+    let n = |v: &JSONValue| v.get("n").and_then(JSONValue::as_f64);
+    list.sort_array_by(|a, b| n(a).partial_cmp(&n(b)).unwrap());
+    assert_eq!(list, json!([[2, 1], {"n": 1}, {"n": 2}, {"n": 3}]));
+
+    let mut scalar = json!("x");
+    scalar.sort_array_by(|_, _| unreachable!());
+    assert_eq!(scalar, json!("x"));
+}
+
+#[test]
+fn array_contains_compares_elements() {
+    let list = json!([1, "a", {"b": null}, [2]]);
+    assert!(list.array_contains(&json!("a")));
+    assert!(list.array_contains(&json!({"b": null})));
+    assert!(list.array_contains(&json!([2])));
+    assert!(!list.array_contains(&json!(2)));
+    assert!(!list.array_contains(&json!("b")));
+    assert!(!json!({"a": 1}).array_contains(&json!(1)));
+    assert!(!json!("a").array_contains(&json!("a")));
+}
+
+#[test]
+fn borrows_fields_with_try_from() {
+    let value = json!({"name": "widget", "price": 2.5, "stock": 3, "sale": true});
+    let name = <&str>::try_from(&value["name"]).unwrap();
+    assert_eq!(name, "widget");
+    assert_eq!(f64::try_from(&value["price"]), Ok(2.5));
+    assert_eq!(f64::try_from(&value["stock"]), Ok(3.0));
+    assert_eq!(bool::try_from(&value["sale"]), Ok(true));
+    assert!(<&str>::try_from(&value["price"]).is_err());
+    assert!(bool::try_from(&value["name"]).is_err());
+    // The value is only borrowed.
+    assert_eq!(value["name"], json!("widget"));
+}
+
+#[test]
+fn into_object_and_into_array_hand_back_other_values() {
+    let object = json!({"a": 1}).into_object().unwrap();
+    assert_eq!(object.len(), 1);
+    assert_eq!(object["a"], json!(1));
+    assert_eq!(json!([1, 2]).into_array(), Ok(vec![json!(1), json!(2)]));
+
+    assert_eq!(json!([1]).into_object(), Err(json!([1])));
+    assert_eq!(json!({"a": 1}).into_array(), Err(json!({"a": 1})));
+    assert_eq!(json!("x").into_array(), Err(json!("x")));
+}
+
+#[test]
+fn estimated_size_grows_with_contents() {
+    let scalar = json!(1);
+    let node = scalar.estimated_size();
+    assert!(node > 0);
+    assert_eq!(json!(null).estimated_size(), node);
+    assert_eq!(json!("abcd").estimated_size(), node + 4);
+
+    let records: JSONValue = (0..1000)
+        .map(|i| json!({"id": i, "name": "record", "tags": ["a", "b"]}))
+        .collect();
+    // 1000 records of six nodes each, plus the array, keys and strings.
+    let size = records.estimated_size();
+    assert!(size > 6001 * node + 1000 * (2 + 4 + 4 + 6 + 2));
+    assert!(size < 10 * 6001 * node);
+}
+
+#[test]
+fn keys_and_values() {
+    let doc = json!({"b": 2, "a": 1, "c": [3]});
+    let mut keys: Vec<&str> = doc.keys().map(|k| k.as_str()).collect();
+    keys.sort_unstable();
+    assert_eq!(keys, ["a", "b", "c"]);
+    let mut values: Vec<String> = doc.values().map(JSONValue::to_string).collect();
+    values.sort();
+    assert_eq!(values, ["1", "2", "[3]"]);
+    // Keys and values come out in the same order.
+    for (key, value) in doc.keys().zip(doc.values()) {
+        assert_eq!(&doc[key.as_str()], value);
+    }
+
+    assert_eq!(doc["c"].keys().count(), 0);
+    assert_eq!(doc["c"].values().count(), 0);
+    assert_eq!(json!("s").values().count(), 0);
+}
+
+#[test]
+fn counts_nodes_and_depth() {
+    let flat = json!([1, 2, 3, "x", null]);
+    assert_eq!(flat.count_nodes(), 6);
+    assert_eq!(flat.depth(), 1);
+
+    let nested = json!({"a": {"b": {"c": {"d": [1, {"e": true}]}}}, "f": 2});
+    assert_eq!(nested.count_nodes(), 9);
+    assert_eq!(nested.depth(), 6);
+
+    assert_eq!(json!(1).count_nodes(), 1);
+    assert_eq!(json!(1).depth(), 0);
+    assert_eq!(json!([]).depth(), 1);
+
+    // The same measures the parser limits use.
+    let json = nested.to_string();
+    let parse = |config: ParserConfig| JSON::parse_with_config(&json, &config);
+    assert!(parse(ParserConfig::new().max_depth(6).max_nodes(9)).is_ok());
+    assert!(parse(ParserConfig::new().max_depth(5)).is_err());
+    assert!(parse(ParserConfig::new().max_nodes(8)).is_err());
+}